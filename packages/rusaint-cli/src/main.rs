@@ -1,21 +1,31 @@
 mod semester_type;
 
-use std::{fs::File, io::Write, sync::Arc};
+use std::{
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use clap::{Parser, Subcommand};
 use rusaint::{
-    RusaintError, USaintSession,
+    ApplicationError, RusaintError, USaintSession,
     application::{
         USaintClientBuilder,
+        chapel::ChapelApplication,
         course_schedule::{
             CourseScheduleApplication,
+            diff::LectureDiff,
             model::{Lecture, LectureCategory},
         },
+        personal_course_schedule::{PersonalCourseScheduleApplication, calendar::ScheduleCalendar},
     },
+    model::Date,
 };
 
 use dotenv::dotenv;
 use semester_type::SemesterType;
+use serde::Serialize;
 use serde_json::to_string_pretty;
 
 #[derive(Parser)]
@@ -112,12 +122,49 @@ enum Commands {
         #[arg(short = 's', long)]
         semester: SemesterType,
     },
+    Calendar {
+        #[arg(short = 'y', long)]
+        year: u32,
+        #[arg(short = 's', long)]
+        semester: SemesterType,
+        #[arg(long)]
+        start_date: Date,
+        #[arg(long)]
+        end_date: Date,
+        #[arg(long)]
+        chapel: bool,
+        #[arg(short = 'o', long)]
+        output: Option<String>,
+    },
+    Diff {
+        #[arg(short = 'b', long)]
+        before: PathBuf,
+        #[arg(short = 'a', long)]
+        after: PathBuf,
+        #[arg(short = 'o', long)]
+        output: Option<String>,
+    },
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<RusaintError>> {
     let cli = Cli::parse();
 
+    // 두 JSON 파일을 비교하는 작업은 로그인이 필요하지 않습니다.
+    if let Commands::Diff {
+        before,
+        after,
+        output,
+    } = &cli.command
+    {
+        let diff = LectureDiff::between(&read_json(before), &read_json(after));
+        let file_name = output
+            .clone()
+            .unwrap_or_else(|| format!("{}_{}_diff", file_stem(before), file_stem(after)));
+        create_json(file_name, diff);
+        return Ok(());
+    }
+
     dotenv().ok();
     let id = std::env::var("SSO_ID").expect("SSO_ID 환경변수가 설정되지 않았습니다.");
     let password =
@@ -249,18 +296,82 @@ async fn main() -> Result<(), Box<RusaintError>> {
             let lectures = find_cyber(session.clone(), year, semester).await?;
             create_json(format!("{year}_{semester}_숭사대"), lectures)
         }
+        Commands::Calendar {
+            year,
+            semester,
+            start_date,
+            end_date,
+            chapel,
+            output,
+        } => {
+            let calendar = schedule_calendar(
+                session.clone(),
+                year,
+                semester,
+                start_date,
+                end_date,
+                chapel,
+            )
+            .await?;
+            let file_name = output.unwrap_or_else(|| format!("{year}_{semester}_시간표"));
+            let mut file = File::create(format!("{file_name}.ics")).expect("Failed to create .ics");
+            file.write_all(calendar.to_ical().as_bytes())
+                .expect("Failed to write to file");
+        }
+        Commands::Diff { .. } => unreachable!(),
     };
 
     Ok(())
 }
 
-fn create_json(file_name: String, lectures: Vec<Lecture>) {
-    let json = to_string_pretty(&lectures).expect("Failed to serialize lectures to JSON");
+fn create_json(file_name: String, value: impl Serialize) {
+    let json = to_string_pretty(&value).expect("Failed to serialize to JSON");
     let mut file = File::create(format!("{file_name}.json")).expect("Failed to create .json");
     file.write_all(json.as_bytes())
         .expect("Failed to write to file");
 }
 
+fn read_json(path: &Path) -> Vec<Lecture> {
+    let file = File::open(path).expect("Failed to open .json");
+    serde_json::from_reader(file).expect("Failed to deserialize lectures from JSON")
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+async fn schedule_calendar(
+    session: Arc<USaintSession>,
+    year: u32,
+    semester: SemesterType,
+    start_date: Date,
+    end_date: Date,
+    chapel: bool,
+) -> Result<ScheduleCalendar, RusaintError> {
+    let mut app = USaintClientBuilder::new()
+        .session(session.clone())
+        .build_into::<PersonalCourseScheduleApplication>()
+        .await?;
+    let schedule = app.schedule(year, *semester).await?;
+    let mut calendar = ScheduleCalendar::new(start_date, end_date).with_schedule(&schedule);
+    if chapel {
+        let mut app = USaintClientBuilder::new()
+            .session(session)
+            .build_into::<ChapelApplication>()
+            .await?;
+        match app.information(year, *semester).await {
+            Ok(information) => {
+                calendar = calendar.with_chapel(information.general_information());
+            }
+            Err(RusaintError::ApplicationError(ApplicationError::NoChapelInformation)) => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(calendar)
+}
+
 async fn find_by_lecture(
     session: Arc<USaintSession>,
     year: u32,
@@ -1,6 +1,13 @@
 use std::sync::Arc;
 
-use rusaint::{application::chapel::model::ChapelInformation, model::SemesterType};
+use rusaint::{
+    application::chapel::{
+        model::{ChapelAbsenceRequest, ChapelAttendance, ChapelHistory, ChapelInformation},
+        request::ChapelAbsenceForm,
+        summary::{ChapelPolicy, ChapelSummary},
+    },
+    model::{Date, SemesterType},
+};
 use tokio::sync::RwLock;
 
 use crate::application::model::YearSemester;
@@ -21,6 +28,68 @@ impl ChapelApplication {
         Ok(self.0.write().await.information(year, semester).await?)
     }
 
+    /// 학년도 선택 목록에 있는 모든 학년도의 1학기, 2학기 채플 정보를 가져옵니다.
+    /// 채플 정보가 없는 학기는 결과에서 제외됩니다.
+    pub async fn history(&self) -> Result<ChapelHistory, RusaintError> {
+        Ok(self.0.write().await.history().await?)
+    }
+
+    /// 주어진 학년도 범위(`from_year`..=`to_year`)의 1학기, 2학기 채플 정보를 가져옵니다.
+    /// 채플 정보가 없는 학기는 결과에서 제외됩니다.
+    pub async fn history_between(
+        &self,
+        from_year: u32,
+        to_year: u32,
+    ) -> Result<ChapelHistory, RusaintError> {
+        Ok(self
+            .0
+            .write()
+            .await
+            .history_between(from_year, to_year)
+            .await?)
+    }
+
+    /// 해당 학기의 채플 출결 정보를 주어진 통과 기준으로 요약하고 통과 여부를 예측합니다.
+    pub async fn summary(
+        &self,
+        year: u32,
+        semester: SemesterType,
+        policy: ChapelPolicy,
+    ) -> Result<ChapelSummary, RusaintError> {
+        let information = self.0.write().await.information(year, semester).await?;
+        Ok(information.summary(&policy))
+    }
+
+    /// 해당 학기에 채플 결석신청을 제출하고, 제출 후의 결석신청 목록을 반환합니다.
+    pub async fn submit_absence_request(
+        &self,
+        year: u32,
+        semester: SemesterType,
+        form: ChapelAbsenceForm,
+    ) -> Result<Vec<ChapelAbsenceRequest>, RusaintError> {
+        Ok(self
+            .0
+            .write()
+            .await
+            .submit_absence_request(year, semester, &form)
+            .await?)
+    }
+
+    /// 해당 학기의 채플 결석신청을 취소하고, 취소 후의 결석신청 목록을 반환합니다.
+    pub async fn cancel_absence_request(
+        &self,
+        year: u32,
+        semester: SemesterType,
+        request: ChapelAbsenceRequest,
+    ) -> Result<Vec<ChapelAbsenceRequest>, RusaintError> {
+        Ok(self
+            .0
+            .write()
+            .await
+            .cancel_absence_request(year, semester, &request)
+            .await?)
+    }
+
     /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
     /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
     pub async fn get_selected_semester(&self) -> Result<YearSemester, RusaintError> {
@@ -60,3 +129,35 @@ impl Default for ChapelApplicationBuilder {
         Self::new()
     }
 }
+
+/// 수업일자를 [`Date`]로 변환하여 반환합니다.
+#[uniffi::export]
+pub fn chapel_attendance_parsed_class_date(attendance: ChapelAttendance) -> Option<Date> {
+    attendance.parsed_class_date()
+}
+
+/// 결석시작일자를 [`Date`]로 변환하여 반환합니다.
+#[uniffi::export]
+pub fn chapel_absence_request_parsed_absence_start(request: ChapelAbsenceRequest) -> Option<Date> {
+    request.parsed_absence_start()
+}
+
+/// 결석종료일자를 [`Date`]로 변환하여 반환합니다.
+#[uniffi::export]
+pub fn chapel_absence_request_parsed_absence_end(request: ChapelAbsenceRequest) -> Option<Date> {
+    request.parsed_absence_end()
+}
+
+/// 신청일자를 [`Date`]로 변환하여 반환합니다.
+#[uniffi::export]
+pub fn chapel_absence_request_parsed_application_date(
+    request: ChapelAbsenceRequest,
+) -> Option<Date> {
+    request.parsed_application_date()
+}
+
+/// 승인일자를 [`Date`]로 변환하여 반환합니다.
+#[uniffi::export]
+pub fn chapel_absence_request_parsed_approval_date(request: ChapelAbsenceRequest) -> Option<Date> {
+    request.parsed_approval_date()
+}
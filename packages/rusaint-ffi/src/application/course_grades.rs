@@ -1,7 +1,15 @@
 use std::{collections::HashMap, sync::Arc};
 
 use rusaint::{
-    application::course_grades::model::{ClassGrade, CourseType, GradeSummary, SemesterGrade},
+    application::course_grades::{
+        changes::ClassGradeChanges,
+        model::{
+            AcademicHistory, AllClassGrades, ClassGrade, CourseType, GradeBreakdown, GradeSummary,
+            SemesterGrade,
+        },
+        reconciliation::SummaryReconciliation,
+        standing::{AcademicStanding, StandingPolicy},
+    },
     model::SemesterType,
 };
 use tokio::sync::RwLock;
@@ -46,6 +54,43 @@ impl CourseGradesApplication {
         Ok(self.0.write().await.semesters(course_type).await?)
     }
 
+    /// 학생의 성적 정보가 있는 학위과정 목록을 가져옵니다.
+    pub async fn course_types(&self) -> Result<Vec<CourseType>, RusaintError> {
+        Ok(self.0.write().await.course_types().await?)
+    }
+
+    /// 성적 정보가 있는 모든 학위과정의 전체 성적과 학기별 성적을 가져옵니다.
+    pub async fn academic_history(&self) -> Result<AcademicHistory, RusaintError> {
+        Ok(self.0.write().await.academic_history().await?)
+    }
+
+    /// 학기별 성적을 주어진 학사경고 및 제적 기준으로 분석하여 학사경고 이력, 석차 백분위, 평점 추세 및 제적 위험도를 반환합니다.
+    pub async fn academic_standing(
+        &self,
+        course_type: CourseType,
+        policy: StandingPolicy,
+    ) -> Result<AcademicStanding, RusaintError> {
+        Ok(self
+            .0
+            .write()
+            .await
+            .academic_standing(course_type, &policy)
+            .await?)
+    }
+
+    /// 학적부 성적과 증명 성적의 차이를 과목별로 설명합니다.
+    pub async fn summary_reconciliation(
+        &self,
+        course_type: CourseType,
+    ) -> Result<SummaryReconciliation, RusaintError> {
+        Ok(self
+            .0
+            .write()
+            .await
+            .summary_reconciliation(course_type)
+            .await?)
+    }
+
     /// 주어진 학기의 수업별 성적을 가져옵니다. `include_details`가 `true`인 경우 수업의 상세 성적도 가져옵니다.
     /// 수업의 상세 성적까지 가져올 경우 상세 성적이 있는 수업의 수 만큼 서버에 요청을 보내므로 반드시 상세 성적도 한번에 가져와야 할 때에만 사용하십시오.
     ///
@@ -65,6 +110,39 @@ impl CourseGradesApplication {
             .await?)
     }
 
+    /// 모든 학기(계절학기 포함)의 수업별 성적을 한번에 가져옵니다. `include_details`가 `true`인 경우 수업의 상세 성적도 가져옵니다.
+    /// 특정 학기의 성적을 가져오는 데 실패한 경우 해당 학기는 실패 목록에 포함됩니다.
+    pub async fn all_classes(
+        &self,
+        course_type: CourseType,
+        include_details: bool,
+    ) -> Result<AllClassGrades, RusaintError> {
+        Ok(self
+            .0
+            .write()
+            .await
+            .all_classes(course_type, include_details)
+            .await?)
+    }
+
+    /// 주어진 학기의 수업별 성적을 다시 가져와 이전에 저장한 조회 결과와 비교하여 새로 입력되거나 변경된 성적을 반환합니다.
+    /// 상세 성적의 변경 사항은 `include_details`가 `true`인 경우에만 확인할 수 있습니다.
+    pub async fn class_changes(
+        &self,
+        course_type: CourseType,
+        year: u32,
+        semester: SemesterType,
+        previous: Vec<ClassGrade>,
+        include_details: bool,
+    ) -> Result<ClassGradeChanges, RusaintError> {
+        Ok(self
+            .0
+            .write()
+            .await
+            .class_changes(course_type, year, semester, &previous, include_details)
+            .await?)
+    }
+
     /// 주어진 수업의 상세 성적 정보를 가져옵니다.
     pub async fn class_detail(
         &self,
@@ -81,6 +159,22 @@ impl CourseGradesApplication {
             .await?)
     }
 
+    /// 주어진 수업의 항목별 상세 성적 정보를 유세인트에 표시되는 순서대로 가져옵니다.
+    pub async fn class_breakdown(
+        &self,
+        course_type: CourseType,
+        year: u32,
+        semester: SemesterType,
+        code: &str,
+    ) -> Result<GradeBreakdown, RusaintError> {
+        Ok(self
+            .0
+            .write()
+            .await
+            .class_breakdown(course_type, year, semester, code)
+            .await?)
+    }
+
     /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
     /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
     pub async fn get_selected_semester(&self) -> Result<YearSemester, RusaintError> {
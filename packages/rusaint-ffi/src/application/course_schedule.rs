@@ -1,7 +1,11 @@
 use std::sync::Arc;
 
 use rusaint::{
-    application::course_schedule::model::{Lecture, LectureCategory},
+    application::course_schedule::{
+        history::LectureHistory,
+        model::{Lecture, LectureCategory},
+        query::LectureQuery,
+    },
     model::SemesterType,
 };
 use tokio::sync::RwLock;
@@ -33,6 +37,39 @@ impl CourseScheduleApplication {
             .collect())
     }
 
+    /// 학기, 학년도, 강의 분류를 통해 강의를 찾고, 주어진 질의로 걸러내고 정렬하여 반환합니다.
+    pub async fn find_lectures_with_query(
+        &self,
+        year: u32,
+        semester: SemesterType,
+        lecture_category: &LectureCategory,
+        query: LectureQuery,
+    ) -> Result<Vec<Lecture>, RusaintError> {
+        let mut app = self.0.write().await;
+        let lectures = app.find_lectures(year, semester, lecture_category).await?;
+        Ok(query.apply(lectures))
+    }
+
+    /// 이미 가져온 강의 목록을 주어진 질의로 걸러내고 정렬하여 반환합니다.
+    pub fn filter_lectures(&self, lectures: Vec<Lecture>, query: LectureQuery) -> Vec<Lecture> {
+        query.apply(lectures)
+    }
+
+    /// 과목번호 또는 과목명으로 주어진 범위의 학년도의 모든 학기를 검색하여 과목의 개설 이력을 반환합니다.
+    pub async fn lecture_history(
+        &self,
+        keyword: &str,
+        from_year: u32,
+        to_year: u32,
+    ) -> Result<LectureHistory, RusaintError> {
+        Ok(self
+            .0
+            .write()
+            .await
+            .lecture_history(keyword, from_year, to_year)
+            .await?)
+    }
+
     /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
     /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
     pub async fn get_selected_semester(&self) -> Result<YearSemester, RusaintError> {
@@ -4,6 +4,7 @@ use crate::{error::RusaintError, session::USaintSession};
 use rusaint::application::graduation_requirements::model::{
     GraduationRequirements, GraduationStudent,
 };
+use rusaint::model::Date;
 use tokio::sync::RwLock;
 
 /// [졸업사정표](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW8015)
@@ -56,3 +57,9 @@ impl Default for GraduationRequirementsApplicationBuilder {
         Self::new()
     }
 }
+
+/// 졸업사정일자를 [`Date`]로 변환하여 반환합니다.
+#[uniffi::export]
+pub fn graduation_student_parsed_audit_date(student: GraduationStudent) -> Option<Date> {
+    student.parsed_audit_date()
+}
@@ -2,7 +2,10 @@ use std::sync::Arc;
 
 use crate::application::model::YearSemester;
 use crate::{error::RusaintError, session::USaintSession};
-use rusaint::application::lecture_assessment::model::LectureAssessmentResult;
+use rusaint::application::lecture_assessment::{
+    model::{LectureAssessmentDetail, LectureAssessmentResult},
+    statistics::{AssessmentStatistics, AssessmentTarget},
+};
 use rusaint::model::SemesterType;
 use tokio::sync::RwLock;
 
@@ -34,6 +37,29 @@ impl LectureAssessmentApplication {
             .await?)
     }
 
+    /// 주어진 강의평가 결과의 상세 화면을 열어 문항별 평균 점수와 응답자 수를 가져옵니다.
+    pub async fn assessment_detail(
+        &self,
+        result: LectureAssessmentResult,
+    ) -> Result<LectureAssessmentDetail, RusaintError> {
+        Ok(self.0.write().await.assessment_detail(&result).await?)
+    }
+
+    /// 주어진 범위의 학년도(`from_year`부터 `to_year`까지)의 모든 학기에서 과목코드 또는 교수명으로 강의평가를 검색하여 통계를 계산합니다.
+    pub async fn assessment_statistics(
+        &self,
+        target: AssessmentTarget,
+        from_year: u32,
+        to_year: u32,
+    ) -> Result<AssessmentStatistics, RusaintError> {
+        Ok(self
+            .0
+            .write()
+            .await
+            .assessment_statistics(&target, from_year, to_year)
+            .await?)
+    }
+
     /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
     /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
     pub async fn get_selected_semester(&self) -> Result<YearSemester, RusaintError> {
@@ -23,4 +23,7 @@ pub mod lecture_assessment;
 pub mod scholarships;
 
 /// 플랫폼 지원을 위한 데이터
+///
+/// 레코드는 메소드를 가질 수 없으므로 일자 필드의 `parsed_*` 함수는 `<레코드>_parsed_<필드>` 형태의 함수로 제공됩니다.
+/// 각 함수는 일자 문자열을 플랫폼의 날짜 타입(한국 표준시 기준 자정)으로 변환하며, 값이 비어 있거나 변환할 수 없다면 `None`을 반환합니다.
 pub mod model;
@@ -2,8 +2,14 @@ use std::sync::Arc;
 
 use crate::application::model::YearSemester;
 use crate::{error::RusaintError, session::USaintSession};
-use rusaint::application::personal_course_schedule::model::PersonalCourseSchedule;
-use rusaint::model::SemesterType;
+use rusaint::application::chapel::model::GeneralChapelInformation;
+use rusaint::application::course_schedule::model::Lecture;
+use rusaint::application::personal_course_schedule::calendar::ScheduleCalendar;
+use rusaint::application::personal_course_schedule::catalog::EnrichedSchedule;
+use rusaint::application::personal_course_schedule::model::{
+    CourseScheduleEvent, PersonalCourseSchedule,
+};
+use rusaint::model::{Date, SemesterType};
 use tokio::sync::RwLock;
 
 /// [개인수업시간표](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW2102)
@@ -23,6 +29,21 @@ impl PersonalCourseScheduleApplication {
         Ok(self.0.write().await.schedule(year, semester).await?)
     }
 
+    /// 해당 학기의 시간표를 요일, 시작 시각 순으로 정렬된 수업 일정 목록으로 가져옵니다.
+    pub async fn schedule_events(
+        &self,
+        year: u32,
+        semester: SemesterType,
+    ) -> Result<Vec<CourseScheduleEvent>, RusaintError> {
+        Ok(self
+            .0
+            .write()
+            .await
+            .schedule(year, semester)
+            .await?
+            .events())
+    }
+
     /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
     /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
     pub async fn get_selected_semester(&self) -> Result<YearSemester, RusaintError> {
@@ -31,6 +52,40 @@ impl PersonalCourseScheduleApplication {
     }
 }
 
+/// 시간표와 채플 시간을 학기 시작일부터 종료일까지 매주 반복되는 iCalendar(RFC 5545) 형식의 문자열로 변환합니다.
+#[uniffi::export]
+pub fn schedule_to_icalendar(
+    schedule: PersonalCourseSchedule,
+    chapel: Option<GeneralChapelInformation>,
+    start_date: Date,
+    end_date: Date,
+) -> String {
+    let mut calendar = ScheduleCalendar::new(start_date, end_date).with_schedule(&schedule);
+    if let Some(chapel) = chapel {
+        calendar = calendar.with_chapel(&chapel);
+    }
+    calendar.to_ical()
+}
+
+/// 시간표의 각 수업을 같은 학기의 과목 목록과 연결합니다.
+#[uniffi::export]
+pub fn enrich_schedule(
+    schedule: PersonalCourseSchedule,
+    lectures: Vec<Lecture>,
+) -> EnrichedSchedule {
+    EnrichedSchedule::join(&schedule, &lectures)
+}
+
+/// 주어진 세션으로 해당 학기의 개인 수업 시간표를 가져오고, 시간표의 각 강의명으로 강의시간표를 검색하여 연결합니다.
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn fetch_enriched_schedule(
+    session: Arc<USaintSession>,
+    year: u32,
+    semester: SemesterType,
+) -> Result<EnrichedSchedule, RusaintError> {
+    Ok(EnrichedSchedule::fetch(session.original(), year, semester).await?)
+}
+
 /// [`PersonalCourseScheduleApplication`] 생성을 위한 빌더
 #[derive(uniffi::Object)]
 pub struct PersonalCourseScheduleApplicationBuilder {}
@@ -1,7 +1,11 @@
 use std::sync::Arc;
 
 use crate::{error::RusaintError, session::USaintSession};
-use rusaint::application::scholarships::model::Scholarship;
+use rusaint::application::scholarships::{
+    model::Scholarship,
+    summary::{ScholarshipFilter, ScholarshipSummary},
+};
+use rusaint::model::Date;
 use tokio::sync::RwLock;
 
 /// [장학금수혜내역조회](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW7530n)
@@ -16,6 +20,22 @@ impl ScholarshipsApplication {
     pub async fn scholarships(&self) -> Result<Vec<Scholarship>, RusaintError> {
         Ok(self.0.write().await.scholarships().await?)
     }
+
+    /// 장학금 수혜 내역 중 필터의 조건을 만족하는 장학금만 가져옵니다. 수혜 내역이 없다면 빈 목록을 반환합니다.
+    pub async fn filtered_scholarships(
+        &self,
+        filter: ScholarshipFilter,
+    ) -> Result<Vec<Scholarship>, RusaintError> {
+        Ok(self.0.write().await.filtered_scholarships(&filter).await?)
+    }
+
+    /// 필터의 조건을 만족하는 장학금의 학기별, 년도별 합계와 교체 관계를 계산합니다.
+    pub async fn scholarship_summary(
+        &self,
+        filter: ScholarshipFilter,
+    ) -> Result<ScholarshipSummary, RusaintError> {
+        Ok(self.0.write().await.scholarship_summary(&filter).await?)
+    }
 }
 
 /// [`ScholarshipsApplication`] 생성을 위한 빌더
@@ -49,3 +69,9 @@ impl Default for ScholarshipsApplicationBuilder {
         Self::new()
     }
 }
+
+/// 처리일자를 [`Date`]로 변환하여 반환합니다.
+#[uniffi::export]
+pub fn scholarship_parsed_processed_at(scholarship: Scholarship) -> Option<Date> {
+    scholarship.parsed_processed_at()
+}
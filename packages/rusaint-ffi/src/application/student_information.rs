@@ -2,10 +2,13 @@ use std::sync::Arc;
 
 use crate::{error::RusaintError, session::USaintSession};
 use rusaint::application::student_information::model::{
-    StudentAcademicRecords, StudentBankAccount, StudentFamily, StudentGraduation,
-    StudentInformation, StudentQualification, StudentReligion, StudentResearchBankAccount,
+    StudentAcademicRecord, StudentAcademicRecords, StudentBankAccount, StudentFamily,
+    StudentForignStudyInformation, StudentGraduation, StudentInformation,
+    StudentLifelongInformation, StudentQualification, StudentReligion, StudentResearchBankAccount,
+    StudentTeachingMajorInformation, StudentTeachingPluralMajorInformation, StudentTransferRecord,
     StudentTransferRecords, StudentWorkInformation,
 };
+use rusaint::model::Date;
 use tokio::sync::RwLock;
 
 /// [학생 정보 수정 및 조회](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW1001n)
@@ -99,3 +102,115 @@ impl Default for StudentInformationApplicationBuilder {
         Self::new()
     }
 }
+
+/// 입학년월일을 [`Date`]로 변환하여 반환합니다.
+#[uniffi::export]
+pub fn student_information_parsed_apply_date(information: StudentInformation) -> Option<Date> {
+    information.parsed_apply_date()
+}
+
+/// 시작일을 [`Date`]로 변환하여 반환합니다.
+#[uniffi::export]
+pub fn student_academic_record_parsed_start_date(
+    academic_record: StudentAcademicRecord,
+) -> Option<Date> {
+    academic_record.parsed_start_date()
+}
+
+/// 종료일을 [`Date`]로 변환하여 반환합니다.
+#[uniffi::export]
+pub fn student_academic_record_parsed_end_date(
+    academic_record: StudentAcademicRecord,
+) -> Option<Date> {
+    academic_record.parsed_end_date()
+}
+
+/// 처리일자를 [`Date`]로 변환하여 반환합니다.
+#[uniffi::export]
+pub fn student_academic_record_parsed_process_date(
+    academic_record: StudentAcademicRecord,
+) -> Option<Date> {
+    academic_record.parsed_process_date()
+}
+
+/// 학위수여일을 [`Date`]로 변환하여 반환합니다.
+#[uniffi::export]
+pub fn student_graduation_parsed_graduation_date(graduation: StudentGraduation) -> Option<Date> {
+    graduation.parsed_graduation_date()
+}
+
+/// 입학일자를 [`Date`]로 변환하여 반환합니다.
+#[uniffi::export]
+pub fn student_transfer_record_parsed_admission_date(
+    transfer_record: StudentTransferRecord,
+) -> Option<Date> {
+    transfer_record.parsed_admission_date()
+}
+
+/// 신앙시작일을 [`Date`]로 변환하여 반환합니다.
+#[uniffi::export]
+pub fn student_religion_parsed_start_date(religion: StudentReligion) -> Option<Date> {
+    religion.parsed_start_date()
+}
+
+/// 세례일자를 [`Date`]로 변환하여 반환합니다.
+#[uniffi::export]
+pub fn student_religion_parsed_baptism_date(religion: StudentReligion) -> Option<Date> {
+    religion.parsed_baptism_date()
+}
+
+/// 선발일자를 [`Date`]로 변환하여 반환합니다.
+#[uniffi::export]
+pub fn student_teaching_major_information_parsed_initiation_date(
+    teaching_major_information: StudentTeachingMajorInformation,
+) -> Option<Date> {
+    teaching_major_information.parsed_initiation_date()
+}
+
+/// 교원자격증 발급일자를 [`Date`]로 변환하여 반환합니다.
+#[uniffi::export]
+pub fn student_teaching_major_information_parsed_qualification_date(
+    teaching_major_information: StudentTeachingMajorInformation,
+) -> Option<Date> {
+    teaching_major_information.parsed_qualification_date()
+}
+
+/// 교원자격증 발급일자를 [`Date`]로 변환하여 반환합니다.
+#[uniffi::export]
+pub fn student_teaching_plural_major_information_parsed_qualification_date(
+    teaching_plural_major_information: StudentTeachingPluralMajorInformation,
+) -> Option<Date> {
+    teaching_plural_major_information.parsed_qualification_date()
+}
+
+/// 신청일자를 [`Date`]로 변환하여 반환합니다.
+#[uniffi::export]
+pub fn student_lifelong_information_parsed_apply_date(
+    lifelong_information: StudentLifelongInformation,
+) -> Option<Date> {
+    lifelong_information.parsed_apply_date()
+}
+
+/// 자격증 발급일자를 [`Date`]로 변환하여 반환합니다.
+#[uniffi::export]
+pub fn student_lifelong_information_parsed_qualification_date(
+    lifelong_information: StudentLifelongInformation,
+) -> Option<Date> {
+    lifelong_information.parsed_qualification_date()
+}
+
+/// 승인일자를 [`Date`]로 변환하여 반환합니다.
+#[uniffi::export]
+pub fn student_forign_study_information_parsed_approval_date(
+    forign_study_information: StudentForignStudyInformation,
+) -> Option<Date> {
+    forign_study_information.parsed_approval_date()
+}
+
+/// 발급일자를 [`Date`]로 변환하여 반환합니다.
+#[uniffi::export]
+pub fn student_forign_study_information_parsed_issue_date(
+    forign_study_information: StudentForignStudyInformation,
+) -> Option<Date> {
+    forign_study_information.parsed_issue_date()
+}
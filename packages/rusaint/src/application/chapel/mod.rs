@@ -1,8 +1,14 @@
-use model::{ChapelAbsenceRequest, ChapelAttendance, ChapelInformation, GeneralChapelInformation};
+use model::{
+    ChapelAbsenceRequest, ChapelAbsenceRequestStatus, ChapelAttendance, ChapelHistory,
+    ChapelInformation, GeneralChapelInformation,
+};
+use request::ChapelAbsenceForm;
 
 use super::{USaintApplication, USaintClient};
+use crate::application::utils::input_field::InputFieldExt as _;
+use crate::application::utils::popup::{close_popups, popup_messages};
 use crate::application::utils::semester::get_selected_semester;
-use crate::{RusaintError, model::SemesterType};
+use crate::{RusaintError, error::ApplicationError, model::SemesterType};
 
 use wdpe::command::WebDynproCommandExecutor;
 use wdpe::element::parser::ElementParser;
@@ -10,11 +16,21 @@ use wdpe::{
     body::Body,
     command::element::{
         action::ButtonPressEventCommand,
-        selection::{ComboBoxLSDataCommand, ComboBoxSelectEventCommand},
+        complex::SapTableBodyCommand,
+        selection::{
+            ComboBoxItemListBoxCommand, ComboBoxLSDataCommand, ComboBoxSelectEventCommand,
+            ListBoxItemInfoCommand,
+        },
     },
     define_elements,
-    element::{action::Button, selection::ComboBox},
+    element::{
+        action::Button,
+        complex::sap_table::property::{AccessType, SapTableRowType},
+        selection::{ComboBox, list_box::item::ListBoxItemInfo},
+        text::InputField,
+    },
     error::{ElementError, WebDynproError},
+    event::Event,
 };
 
 /// [채플정보조회](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMW3681)
@@ -42,6 +58,16 @@ impl<'a> ChapelApplication {
         BTN_SEL: Button<'a> = "ZCMW3681.ID_0001:V_MAIN.BTN_SEL";
     }
 
+    // Elements for Absence Request
+    define_elements! {
+        ABSNC_BEGDA: InputField<'a> = "ZCMW3681.ID_0001:V_MAIN.TC_ABSNC_BEGDA";
+        ABSNC_ENDDA: InputField<'a> = "ZCMW3681.ID_0001:V_MAIN.TC_ABSNC_ENDDA";
+        ABSNC_DETAIL: ComboBox<'a> = "ZCMW3681.ID_0001:V_MAIN.TC_ABSNC_DETAIL";
+        ABSNC_REASON: InputField<'a> = "ZCMW3681.ID_0001:V_MAIN.TC_ABSNC_REASON";
+        BTN_ABSNC_SAVE: Button<'a> = "ZCMW3681.ID_0001:V_MAIN.BTN_ABSNC_SAVE";
+        BTN_ABSNC_CANCEL: Button<'a> = "ZCMW3681.ID_0001:V_MAIN.BTN_ABSNC_CANCEL";
+    }
+
     fn semester_to_key(semester: SemesterType) -> &'static str {
         match semester {
             SemesterType::One => "090",
@@ -116,6 +142,244 @@ impl<'a> ChapelApplication {
         ))
     }
 
+    /// 학년도 선택 목록에 있는 모든 학년도의 1학기, 2학기 채플 정보를 가져옵니다.
+    /// 채플 정보가 없는 학기는 채플을 수강하지 않은 학기로 보고 결과에서 제외합니다.
+    /// ### 예시
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// # use std::sync::Arc;
+    /// # use rusaint::USaintSession;
+    /// # use rusaint::application::USaintClientBuilder;
+    /// # use rusaint::application::chapel::ChapelApplication;
+    /// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+    /// let mut app = USaintClientBuilder::new().session(session).build_into::<ChapelApplication>().await.unwrap();
+    /// let history = app.history().await.unwrap();
+    /// println!("통과한 채플: {}학기", history.passed_semesters());
+    /// # })
+    /// ```
+    pub async fn history(&mut self) -> Result<ChapelHistory, RusaintError> {
+        let years = self.available_years()?;
+        self.history_of(years).await
+    }
+
+    /// 주어진 학년도 범위(`from_year`..=`to_year`)에서 학년도 선택 목록에 있는 학년도의 1학기, 2학기 채플 정보를 가져옵니다.
+    /// 학기마다 조회가 필요하므로 학생이 재학한 학년도만 조회하려면 [`history()`](Self::history) 대신 이 함수를 이용하십시오.
+    /// 채플 정보가 없는 학기는 채플을 수강하지 않은 학기로 보고 결과에서 제외합니다.
+    /// ### 예시
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// # use std::sync::Arc;
+    /// # use rusaint::USaintSession;
+    /// # use rusaint::application::USaintClientBuilder;
+    /// # use rusaint::application::chapel::ChapelApplication;
+    /// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+    /// let mut app = USaintClientBuilder::new().session(session).build_into::<ChapelApplication>().await.unwrap();
+    /// let history = app.history_between(2021, 2024).await.unwrap();
+    /// println!("통과한 채플: {}학기", history.passed_semesters());
+    /// # })
+    /// ```
+    pub async fn history_between(
+        &mut self,
+        from_year: u32,
+        to_year: u32,
+    ) -> Result<ChapelHistory, RusaintError> {
+        let years = self
+            .available_years()?
+            .into_iter()
+            .filter(|year| (from_year..=to_year).contains(year))
+            .collect();
+        self.history_of(years).await
+    }
+
+    async fn history_of(&mut self, years: Vec<u32>) -> Result<ChapelHistory, RusaintError> {
+        let mut semesters = Vec::new();
+        for year in years {
+            for semester in [SemesterType::One, SemesterType::Two] {
+                match self.information(year, semester).await {
+                    Ok(information) => semesters.push(information),
+                    Err(RusaintError::ApplicationError(ApplicationError::NoChapelInformation)) => {
+                        continue;
+                    }
+                    Err(err) => return Err(err),
+                }
+            }
+        }
+        Ok(ChapelHistory::new(semesters))
+    }
+
+    /// 해당 학기에 채플 결석신청을 제출하고, 제출 후의 결석신청 목록을 반환합니다.
+    ///
+    /// 제출 전에 [`ChapelAbsenceForm::validate()`]로 해당 학기의 기존 결석신청과 함께 신청 내용을 검증합니다.
+    /// 유세인트가 신청을 받아들이지 않으면 팝업에 표시된 메시지와 함께 [`ApplicationError::ChapelAbsenceRequestRejected`]를 반환합니다.
+    pub async fn submit_absence_request(
+        &mut self,
+        year: u32,
+        semester: SemesterType,
+        form: &ChapelAbsenceForm,
+    ) -> Result<Vec<ChapelAbsenceRequest>, RusaintError> {
+        let information = self.information(year, semester).await?;
+        form.validate(information.absence_requests())?;
+        let events = {
+            let parser = ElementParser::new(self.body());
+            let detail_key = Self::absence_detail_key(&parser, form.absence_detail())?;
+            vec![
+                parser
+                    .element_from_def(&Self::ABSNC_BEGDA)?
+                    .change(&form.saint_absence_start())?,
+                parser
+                    .element_from_def(&Self::ABSNC_ENDDA)?
+                    .change(&form.saint_absence_end())?,
+                parser.read(ComboBoxSelectEventCommand::new(
+                    Self::ABSNC_DETAIL,
+                    &detail_key,
+                    false,
+                ))?,
+                parser
+                    .element_from_def(&Self::ABSNC_REASON)?
+                    .change(form.reason())?,
+                parser.read(ButtonPressEventCommand::new(Self::BTN_ABSNC_SAVE))?,
+            ]
+        };
+        let messages = self.process_absence_events(events).await?;
+        let requests = self
+            .information(year, semester)
+            .await?
+            .absence_requests()
+            .to_vec();
+        let submitted = requests.iter().any(|request| {
+            form.matches(request) && request.request_status() != ChapelAbsenceRequestStatus::Denied
+        });
+        if !submitted {
+            return Err(Self::rejected(messages));
+        }
+        Ok(requests)
+    }
+
+    /// 해당 학기의 채플 결석신청을 취소하고, 취소 후의 결석신청 목록을 반환합니다.
+    ///
+    /// 승인 또는 거부되지 않은 신청만 취소할 수 있습니다. 해당 학기에 같은 신청이 없으면 [`ApplicationError::NoChapelAbsenceRequest`]를,
+    /// 유세인트가 취소를 받아들이지 않으면 팝업에 표시된 메시지와 함께 [`ApplicationError::ChapelAbsenceRequestRejected`]를 반환합니다.
+    pub async fn cancel_absence_request(
+        &mut self,
+        year: u32,
+        semester: SemesterType,
+        request: &ChapelAbsenceRequest,
+    ) -> Result<Vec<ChapelAbsenceRequest>, RusaintError> {
+        if request.request_status() != ChapelAbsenceRequestStatus::Requested {
+            return Err(ApplicationError::InvalidChapelAbsenceRequest(
+                "승인 또는 거부된 결석신청은 취소할 수 없습니다.".to_string(),
+            )
+            .into());
+        }
+        let information = self.information(year, semester).await?;
+        let index = information
+            .absence_requests()
+            .iter()
+            .position(|other| Self::is_same_request(request, other))
+            .ok_or(ApplicationError::NoChapelAbsenceRequest)?;
+        let events = {
+            let parser = ElementParser::new(self.body());
+            let table_body = parser.read(SapTableBodyCommand::new(
+                ChapelAbsenceRequest::TABLE02_CP_CP,
+            ))?;
+            let row = table_body
+                .iter()
+                .filter(|row| !matches!(row.row_type(), SapTableRowType::Header))
+                .nth(index)
+                .ok_or(ApplicationError::NoChapelAbsenceRequest)?;
+            let row_index = row
+                .row_index()
+                .ok_or(ApplicationError::NoChapelAbsenceRequest)?;
+            let table = parser.element_from_def(&ChapelAbsenceRequest::TABLE02_CP_CP)?;
+            vec![
+                table.row_select(
+                    row_index as i32,
+                    row.user_data().unwrap_or_default(),
+                    "",
+                    AccessType::Standard,
+                    "",
+                )?,
+                parser.read(ButtonPressEventCommand::new(Self::BTN_ABSNC_CANCEL))?,
+            ]
+        };
+        let messages = self.process_absence_events(events).await?;
+        let requests = self
+            .information(year, semester)
+            .await?
+            .absence_requests()
+            .to_vec();
+        let cancelled = !requests.iter().any(|other| {
+            Self::is_same_request(request, other)
+                && other.request_status() == ChapelAbsenceRequestStatus::Requested
+        });
+        if !cancelled {
+            return Err(Self::rejected(messages));
+        }
+        Ok(requests)
+    }
+
+    fn absence_detail_key(parser: &ElementParser, detail: &str) -> Result<String, RusaintError> {
+        let item_box = parser.read(ComboBoxItemListBoxCommand::new(Self::ABSNC_DETAIL))?;
+        parser
+            .read(ListBoxItemInfoCommand::new(item_box))?
+            .into_iter()
+            .find_map(|item| match item {
+                ListBoxItemInfo::Item { key, value1, .. } if value1.trim() == detail.trim() => {
+                    Some(key)
+                }
+                _ => None,
+            })
+            .ok_or_else(|| {
+                ApplicationError::InvalidChapelAbsenceRequest(format!(
+                    "결석구분상세 '{detail}'을(를) 선택할 수 없습니다."
+                ))
+                .into()
+            })
+    }
+
+    async fn process_absence_events(
+        &mut self,
+        events: Vec<Event>,
+    ) -> Result<Vec<String>, RusaintError> {
+        for event in events {
+            self.client.process_event(false, event).await?;
+        }
+        let messages = popup_messages(self.body());
+        close_popups(&mut self.client).await?;
+        Ok(messages)
+    }
+
+    fn is_same_request(request: &ChapelAbsenceRequest, other: &ChapelAbsenceRequest) -> bool {
+        request.parsed_absence_start() == other.parsed_absence_start()
+            && request.parsed_absence_end() == other.parsed_absence_end()
+            && request.absence_detail().trim() == other.absence_detail().trim()
+    }
+
+    fn rejected(messages: Vec<String>) -> RusaintError {
+        let message = if messages.is_empty() {
+            "유세인트가 결석신청을 처리하지 않았습니다.".to_string()
+        } else {
+            messages.join(" ")
+        };
+        ApplicationError::ChapelAbsenceRequestRejected(message).into()
+    }
+
+    fn available_years(&self) -> Result<Vec<u32>, RusaintError> {
+        let parser = ElementParser::new(self.body());
+        let item_box = parser.read(ComboBoxItemListBoxCommand::new(Self::SEL_PERYR))?;
+        let mut years: Vec<u32> = parser
+            .read(ListBoxItemInfoCommand::new(item_box))?
+            .iter()
+            .filter_map(|item| match item {
+                ListBoxItemInfo::Item { key, .. } => key.trim().parse().ok(),
+                ListBoxItemInfo::ActionItem { .. } => None,
+            })
+            .collect();
+        years.sort_unstable();
+        years.dedup();
+        Ok(years)
+    }
+
     /// 현재 페이지에 선택된 년도와 학기를 가져옵니다. 최초 로드 시 현재 학기를 가져올 가능성이 있습니다.
     /// 하지만 이 애플리케이션의 다른 함수를 호출하여 한번 정보를 가져왔다면 마지막으로 가져온 정보의 학기가 반환되므로 주의하여야 하며, 신뢰할 수 있는 현재 학기의 원천으로 사용되어서는 안됩니다.
     pub fn get_selected_semester(&self) -> Result<(u32, SemesterType), RusaintError> {
@@ -129,3 +393,7 @@ impl<'a> ChapelApplication {
 
 /// [`ChapelApplication`] 애플리케이션에 사용되는 데이터
 pub mod model;
+/// 채플 결석신청 내용 작성 및 검증
+pub mod request;
+/// 채플 출결 요약 및 통과 예측
+pub mod summary;
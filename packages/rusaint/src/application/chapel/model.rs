@@ -5,8 +5,13 @@ use serde::{
     de::{IntoDeserializer, value::MapDeserializer},
 };
 
+use crate::application::chapel::summary::{ChapelPolicy, ChapelSummary};
 use crate::application::utils::de_with::{deserialize_semester_type, deserialize_u32_string};
-use crate::{RusaintError, error::ApplicationError, model::SemesterType};
+use crate::{
+    RusaintError,
+    error::ApplicationError,
+    model::{Date, SemesterType, parse_date},
+};
 use wdpe::command::WebDynproCommandExecutor;
 use wdpe::element::parser::ElementParser;
 use wdpe::{
@@ -78,6 +83,47 @@ impl ChapelInformation {
     pub fn absence_requests(&self) -> &[ChapelAbsenceRequest] {
         &self.absence_requests
     }
+
+    /// 주어진 통과 기준으로 출결 정보를 요약하고 통과 여부를 예측합니다.
+    pub fn summary(&self, policy: &ChapelPolicy) -> ChapelSummary {
+        ChapelSummary::new(self, policy)
+    }
+}
+
+/// 여러 학기의 채플 정보
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ChapelHistory {
+    semesters: Vec<ChapelInformation>,
+}
+
+impl ChapelHistory {
+    pub(crate) fn new(mut semesters: Vec<ChapelInformation>) -> Self {
+        semesters.sort_by_key(|information| (information.year, information.semester));
+        Self { semesters }
+    }
+
+    /// 채플 정보가 있는 학기의 채플 정보를 학기 순으로 반환합니다.
+    pub fn semesters(&self) -> &[ChapelInformation] {
+        &self.semesters
+    }
+
+    /// 채플을 통과한 학기 수를 반환합니다.
+    pub fn passed_semesters(&self) -> u32 {
+        self.count(ChapelResult::Pass)
+    }
+
+    /// 채플을 통과하지 못한 학기 수를 반환합니다.
+    pub fn failed_semesters(&self) -> u32 {
+        self.count(ChapelResult::Fail)
+    }
+
+    fn count(&self, result: ChapelResult) -> u32 {
+        self.semesters
+            .iter()
+            .filter(|information| information.general_information.result_status() == result)
+            .count() as u32
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -168,6 +214,11 @@ impl<'a> GeneralChapelInformation {
         &self.result
     }
 
+    /// 성적을 [`ChapelResult`]로 변환하여 반환합니다.
+    pub fn result_status(&self) -> ChapelResult {
+        ChapelResult::from_result(&self.result)
+    }
+
     /// 비고 내용을 반환합니다.
     pub fn note(&self) -> &str {
         &self.note
@@ -250,6 +301,11 @@ impl<'a> ChapelAttendance {
         &self.class_date
     }
 
+    /// 수업일자를 [`Date`]로 변환하여 반환합니다.
+    pub fn parsed_class_date(&self) -> Option<Date> {
+        parse_date(self.class_date())
+    }
+
     /// 강의구분을 반환합니다.
     pub fn category(&self) -> &str {
         &self.category
@@ -275,6 +331,11 @@ impl<'a> ChapelAttendance {
         &self.attendance
     }
 
+    /// 출결상태를 [`ChapelAttendanceStatus`]로 변환하여 반환합니다.
+    pub fn attendance_status(&self) -> ChapelAttendanceStatus {
+        ChapelAttendanceStatus::from_attendance(&self.attendance)
+    }
+
     /// 평가 내용을 반환합니다.
     pub fn result(&self) -> &str {
         &self.result
@@ -339,7 +400,7 @@ pub struct ChapelAbsenceRequest {
 
 impl<'a> ChapelAbsenceRequest {
     define_elements! {
-        TABLE02_CP_CP: SapTable<'a> = "ZCMW3681.ID_0001:V_MAIN.TABLE02_CP_CP";
+        pub(super) TABLE02_CP_CP: SapTable<'a> = "ZCMW3681.ID_0001:V_MAIN.TABLE02_CP_CP";
     }
     pub(crate) fn with_parser(parser: &'a ElementParser) -> Result<Vec<Self>, RusaintError> {
         let table = parser.read(SapTableBodyCommand::new(Self::TABLE02_CP_CP))?;
@@ -379,11 +440,21 @@ impl<'a> ChapelAbsenceRequest {
         &self.absence_start
     }
 
+    /// 결석시작일자를 [`Date`]로 변환하여 반환합니다.
+    pub fn parsed_absence_start(&self) -> Option<Date> {
+        parse_date(self.absence_start())
+    }
+
     /// 결석종료일자를 반환합니다.
     pub fn absence_end(&self) -> &str {
         &self.absence_end
     }
 
+    /// 결석종료일자를 [`Date`]로 변환하여 반환합니다.
+    pub fn parsed_absence_end(&self) -> Option<Date> {
+        parse_date(self.absence_end())
+    }
+
     /// 국문 결석사유를 반환합니다.
     pub fn absence_reason_kr(&self) -> &str {
         &self.absence_reason_kr
@@ -399,11 +470,21 @@ impl<'a> ChapelAbsenceRequest {
         &self.application_date
     }
 
+    /// 신청일자를 [`Date`]로 변환하여 반환합니다.
+    pub fn parsed_application_date(&self) -> Option<Date> {
+        parse_date(self.application_date())
+    }
+
     /// 승인일자를 반환합니다.
     pub fn approval_date(&self) -> &str {
         &self.approval_date
     }
 
+    /// 승인일자를 [`Date`]로 변환하여 반환합니다.
+    pub fn parsed_approval_date(&self) -> Option<Date> {
+        parse_date(self.approval_date())
+    }
+
     /// 거부사유를 반환합니다.
     pub fn denial_reason(&self) -> &str {
         &self.denial_reason
@@ -413,6 +494,21 @@ impl<'a> ChapelAbsenceRequest {
     pub fn status(&self) -> &str {
         &self.status
     }
+
+    /// 요청 상태를 [`ChapelAbsenceRequestStatus`]로 변환하여 반환합니다.
+    pub fn request_status(&self) -> ChapelAbsenceRequestStatus {
+        ChapelAbsenceRequestStatus::from_status(&self.status)
+    }
+
+    /// 주어진 수업일자가 결석 기간에 포함되는지 여부를 반환합니다.
+    /// 일자는 `2024.03.05`, `2024-03-05` 등 [`Date`]로 변환할 수 있는 형식이어야 합니다.
+    pub fn covers(&self, date: &str) -> bool {
+        let (Some(date), Some(start)) = (parse_date(date), self.parsed_absence_start()) else {
+            return false;
+        };
+        let end = self.parsed_absence_end().unwrap_or(start);
+        start <= date && date <= end
+    }
 }
 
 impl<'body> FromSapTable<'body> for ChapelAbsenceRequest {
@@ -431,3 +527,87 @@ impl<'body> FromSapTable<'body> for ChapelAbsenceRequest {
         )
     }
 }
+
+/// 채플 수업별 출결상태
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum ChapelAttendanceStatus {
+    /// 출석
+    Attended,
+    /// 결석
+    Absent,
+    /// 지각
+    Late,
+    /// 공결(인정결석)
+    Excused,
+    /// 출결 미입력
+    Unrecorded,
+    /// 기타 출결상태
+    Other(String),
+}
+
+impl ChapelAttendanceStatus {
+    /// 유세인트에 표시되는 출결상태 문자열을 출결상태로 변환합니다.
+    pub fn from_attendance(attendance: &str) -> Self {
+        match attendance.trim() {
+            "" => Self::Unrecorded,
+            "출석" => Self::Attended,
+            "결석" => Self::Absent,
+            "지각" => Self::Late,
+            "공결" | "인정결석" | "출석인정" => Self::Excused,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+/// 채플 성적
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum ChapelResult {
+    /// 통과
+    Pass,
+    /// 미통과
+    Fail,
+    /// 성적 미확정
+    Undetermined,
+    /// 기타 성적
+    Other(String),
+}
+
+impl ChapelResult {
+    /// 유세인트에 표시되는 성적 문자열을 채플 성적으로 변환합니다.
+    pub fn from_result(result: &str) -> Self {
+        match result.trim() {
+            "" => Self::Undetermined,
+            "P" | "Pass" | "PASS" | "합격" | "통과" => Self::Pass,
+            "F" | "Fail" | "FAIL" | "불합격" | "미통과" => Self::Fail,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+/// 채플 결석신청 상태
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum ChapelAbsenceRequestStatus {
+    /// 신청
+    Requested,
+    /// 승인
+    Approved,
+    /// 거부
+    Denied,
+    /// 기타 상태
+    Other(String),
+}
+
+impl ChapelAbsenceRequestStatus {
+    /// 유세인트에 표시되는 결석신청 상태 문자열을 결석신청 상태로 변환합니다.
+    pub fn from_status(status: &str) -> Self {
+        match status.trim() {
+            "신청" | "접수" | "대기" => Self::Requested,
+            "승인" => Self::Approved,
+            "거부" | "반려" | "불가" => Self::Denied,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
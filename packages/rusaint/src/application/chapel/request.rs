@@ -0,0 +1,168 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    RusaintError,
+    application::chapel::model::{ChapelAbsenceRequest, ChapelAbsenceRequestStatus},
+    error::ApplicationError,
+    model::parse_date,
+};
+
+/// 새로 제출할 채플 결석신청 내용
+///
+/// 일자는 `2025.03.18`, `2025-03-18`, `20250318` 등의 형식으로 입력하며, 제출할 때 유세인트에 표시되는 `YYYY.MM.DD` 형식으로 변환됩니다.
+/// 제출 전에 [`ChapelAbsenceForm::validate()`]로 신청 내용을 검증할 수 있으며,
+/// [`ChapelApplication::submit_absence_request()`](super::ChapelApplication::submit_absence_request)로 제출합니다.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ChapelAbsenceForm {
+    /// 결석시작일자
+    absence_start: String,
+    /// 결석종료일자
+    absence_end: String,
+    /// 결석구분상세
+    absence_detail: String,
+    /// 결석사유
+    reason: String,
+}
+
+impl ChapelAbsenceForm {
+    /// 결석 기간, 결석구분, 결석사유로 새로운 결석신청 내용을 만듭니다.
+    pub fn new(
+        absence_start: impl Into<String>,
+        absence_end: impl Into<String>,
+        absence_detail: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self {
+            absence_start: absence_start.into(),
+            absence_end: absence_end.into(),
+            absence_detail: absence_detail.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// 결석시작일자
+    pub fn absence_start(&self) -> &str {
+        &self.absence_start
+    }
+
+    /// 결석종료일자
+    pub fn absence_end(&self) -> &str {
+        &self.absence_end
+    }
+
+    /// 결석구분상세
+    pub fn absence_detail(&self) -> &str {
+        &self.absence_detail
+    }
+
+    /// 결석사유
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    /// 신청 내용을 검증합니다.
+    ///
+    /// 일자 형식이 올바르지 않거나, 시작일자가 종료일자보다 늦거나, 결석구분 또는 결석사유가 비어 있거나,
+    /// 기존 신청(`existing`) 중 거부되지 않은 신청과 기간이 겹치면 [`ApplicationError::InvalidChapelAbsenceRequest`]를 반환합니다.
+    pub fn validate(&self, existing: &[ChapelAbsenceRequest]) -> Result<(), RusaintError> {
+        let invalid = |message: &str| -> RusaintError {
+            ApplicationError::InvalidChapelAbsenceRequest(message.to_string()).into()
+        };
+        let start = parse_date(&self.absence_start)
+            .ok_or_else(|| invalid("결석시작일자 형식이 올바르지 않습니다."))?;
+        let end = parse_date(&self.absence_end)
+            .ok_or_else(|| invalid("결석종료일자 형식이 올바르지 않습니다."))?;
+        if start > end {
+            return Err(invalid("결석시작일자가 결석종료일자보다 늦습니다."));
+        }
+        if self.absence_detail.trim().is_empty() {
+            return Err(invalid("결석구분상세가 비어 있습니다."));
+        }
+        if self.reason.trim().is_empty() {
+            return Err(invalid("결석사유가 비어 있습니다."));
+        }
+        let overlaps = existing
+            .iter()
+            .filter(|request| request.request_status() != ChapelAbsenceRequestStatus::Denied)
+            .any(|request| {
+                let (Some(other_start), Some(other_end)) =
+                    (request.parsed_absence_start(), request.parsed_absence_end())
+                else {
+                    return false;
+                };
+                start <= other_end && other_start <= end
+            });
+        if overlaps {
+            return Err(invalid("기간이 겹치는 결석신청이 이미 있습니다."));
+        }
+        Ok(())
+    }
+
+    /// 유세인트의 결석시작일자 입력란에 입력할 `YYYY.MM.DD` 형식의 일자
+    pub(crate) fn saint_absence_start(&self) -> String {
+        saint_date(&self.absence_start)
+    }
+
+    /// 유세인트의 결석종료일자 입력란에 입력할 `YYYY.MM.DD` 형식의 일자
+    pub(crate) fn saint_absence_end(&self) -> String {
+        saint_date(&self.absence_end)
+    }
+
+    /// 결석신청 목록의 신청이 이 신청 내용으로 제출된 신청인지 확인합니다.
+    pub(crate) fn matches(&self, request: &ChapelAbsenceRequest) -> bool {
+        parse_date(&self.absence_start) == request.parsed_absence_start()
+            && parse_date(&self.absence_end) == request.parsed_absence_end()
+            && self.absence_detail.trim() == request.absence_detail().trim()
+    }
+}
+
+/// 일자를 유세인트에 표시되는 `YYYY.MM.DD` 형식으로 변환합니다. 변환할 수 없는 일자는 그대로 반환합니다.
+fn saint_date(date: &str) -> String {
+    parse_date(date)
+        .map(|date| format!("{:04}.{:02}.{:02}", date.year(), date.month(), date.day()))
+        .unwrap_or_else(|| date.trim().to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::ChapelAbsenceForm;
+    use crate::{RusaintError, error::ApplicationError};
+
+    #[test]
+    fn validate_form() {
+        let valid = ChapelAbsenceForm::new("2025.03.18", "2025-03-19", "질병", "병원 진료");
+        assert!(valid.validate(&[]).is_ok());
+        let reversed = ChapelAbsenceForm::new("2025.03.20", "2025.03.18", "질병", "병원 진료");
+        assert!(matches!(
+            reversed.validate(&[]),
+            Err(RusaintError::ApplicationError(
+                ApplicationError::InvalidChapelAbsenceRequest(_)
+            ))
+        ));
+        let empty_reason = ChapelAbsenceForm::new("2025.03.18", "2025.03.18", "질병", " ");
+        assert!(matches!(
+            empty_reason.validate(&[]),
+            Err(RusaintError::ApplicationError(
+                ApplicationError::InvalidChapelAbsenceRequest(message)
+            )) if message == "결석사유가 비어 있습니다."
+        ));
+        let invalid_date = ChapelAbsenceForm::new("2025.13.01", "2025.13.01", "질병", "사유");
+        assert!(matches!(
+            invalid_date.validate(&[]),
+            Err(RusaintError::ApplicationError(
+                ApplicationError::InvalidChapelAbsenceRequest(message)
+            )) if message == "결석시작일자 형식이 올바르지 않습니다."
+        ));
+    }
+
+    #[test]
+    fn normalize_dates() {
+        let form = ChapelAbsenceForm::new("2025-03-19", "20250320", "질병", "병원 진료");
+        assert_eq!(form.saint_absence_start(), "2025.03.19");
+        assert_eq!(form.saint_absence_end(), "2025.03.20");
+        let displayed = ChapelAbsenceForm::new("2025.03.19", "2025/3/20", "질병", "병원 진료");
+        assert_eq!(displayed.saint_absence_start(), "2025.03.19");
+        assert_eq!(displayed.saint_absence_end(), "2025.03.20");
+    }
+}
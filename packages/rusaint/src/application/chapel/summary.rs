@@ -0,0 +1,281 @@
+use serde::{Deserialize, Serialize};
+
+use crate::application::chapel::model::{
+    ChapelAbsenceRequestStatus, ChapelAttendanceStatus, ChapelInformation, ChapelResult,
+};
+
+/// 채플 통과 기준
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ChapelPolicy {
+    /// 통과할 수 있는 최대 결석 횟수
+    #[cfg_attr(feature = "uniffi", uniffi(default = 3))]
+    max_absences: u32,
+    /// 결석 1회로 취급되는 지각 횟수, 0이면 지각은 결석으로 취급하지 않습니다.
+    #[cfg_attr(feature = "uniffi", uniffi(default = 2))]
+    lates_per_absence: u32,
+}
+
+impl Default for ChapelPolicy {
+    fn default() -> Self {
+        Self {
+            max_absences: 3,
+            lates_per_absence: 2,
+        }
+    }
+}
+
+impl ChapelPolicy {
+    /// 주어진 기준으로 새로운 [`ChapelPolicy`]를 만듭니다.
+    pub fn new(max_absences: u32, lates_per_absence: u32) -> Self {
+        Self {
+            max_absences,
+            lates_per_absence,
+        }
+    }
+
+    /// 통과할 수 있는 최대 결석 횟수
+    pub fn max_absences(&self) -> u32 {
+        self.max_absences
+    }
+
+    /// 결석 1회로 취급되는 지각 횟수
+    pub fn lates_per_absence(&self) -> u32 {
+        self.lates_per_absence
+    }
+}
+
+/// 채플 통과 예상 결과
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum ChapelPrediction {
+    /// 통과 확정
+    Passed,
+    /// 미통과 확정
+    Failed,
+    /// 통과 예상
+    LikelyPass,
+    /// 남은 결석 가능 횟수가 없어 한 번 더 결석하면 미통과
+    AtRisk,
+    /// 결석 횟수가 기준을 넘어 미통과 예상
+    LikelyFail,
+}
+
+/// 한 학기의 채플 출결 요약
+///
+/// 승인된 결석신청 기간에 포함되는 결석과 지각은 공결로 취급합니다.
+/// ### 예시
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// # use std::sync::Arc;
+/// # use rusaint::USaintSession;
+/// # use rusaint::application::USaintClientBuilder;
+/// # use rusaint::application::chapel::{ChapelApplication, summary::ChapelPolicy};
+/// # use rusaint::model::SemesterType;
+/// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+/// let mut app = USaintClientBuilder::new().session(session).build_into::<ChapelApplication>().await.unwrap();
+/// let information = app.information(2025, SemesterType::One).await.unwrap();
+/// let summary = information.summary(&ChapelPolicy::default());
+/// println!("남은 결석 가능 횟수: {}, 예상: {:?}", summary.remaining_absences(), summary.prediction());
+/// # })
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ChapelSummary {
+    /// 출석 횟수
+    attended: u32,
+    /// 결석 횟수
+    absent: u32,
+    /// 지각 횟수
+    late: u32,
+    /// 공결 횟수
+    excused: u32,
+    /// 출결 미입력 횟수
+    unrecorded: u32,
+    /// 통과 기준에 반영되는 결석 횟수
+    effective_absences: u32,
+    /// 남은 결석 가능 횟수
+    remaining_absences: u32,
+    /// 통과 예상 결과
+    prediction: ChapelPrediction,
+}
+
+impl ChapelSummary {
+    pub(crate) fn new(information: &ChapelInformation, policy: &ChapelPolicy) -> Self {
+        let approved: Vec<_> = information
+            .absence_requests()
+            .iter()
+            .filter(|request| request.request_status() == ChapelAbsenceRequestStatus::Approved)
+            .collect();
+        let (mut attended, mut absent, mut late, mut excused, mut unrecorded): (
+            u32,
+            u32,
+            u32,
+            u32,
+            u32,
+        ) = (0, 0, 0, 0, 0);
+        for attendance in information.attendances() {
+            let status = attendance.attendance_status();
+            let covered = approved
+                .iter()
+                .any(|request| request.covers(attendance.class_date()));
+            match status {
+                ChapelAttendanceStatus::Absent | ChapelAttendanceStatus::Late if covered => {
+                    excused += 1
+                }
+                ChapelAttendanceStatus::Attended => attended += 1,
+                ChapelAttendanceStatus::Absent => absent += 1,
+                ChapelAttendanceStatus::Late => late += 1,
+                ChapelAttendanceStatus::Excused => excused += 1,
+                ChapelAttendanceStatus::Unrecorded | ChapelAttendanceStatus::Other(_) => {
+                    unrecorded += 1
+                }
+            }
+        }
+        let effective_absences = if information.attendances().is_empty() {
+            information.general_information().absence_time()
+        } else {
+            absent + late.checked_div(policy.lates_per_absence).unwrap_or(0)
+        };
+        let remaining_absences = policy.max_absences.saturating_sub(effective_absences);
+        let prediction = match information.general_information().result_status() {
+            ChapelResult::Pass => ChapelPrediction::Passed,
+            ChapelResult::Fail => ChapelPrediction::Failed,
+            _ if effective_absences > policy.max_absences => ChapelPrediction::LikelyFail,
+            _ if remaining_absences == 0 => ChapelPrediction::AtRisk,
+            _ => ChapelPrediction::LikelyPass,
+        };
+        Self {
+            attended,
+            absent,
+            late,
+            excused,
+            unrecorded,
+            effective_absences,
+            remaining_absences,
+            prediction,
+        }
+    }
+
+    /// 출석 횟수
+    pub fn attended(&self) -> u32 {
+        self.attended
+    }
+
+    /// 결석 횟수(승인된 결석신청 기간의 결석 제외)
+    pub fn absent(&self) -> u32 {
+        self.absent
+    }
+
+    /// 지각 횟수(승인된 결석신청 기간의 지각 제외)
+    pub fn late(&self) -> u32 {
+        self.late
+    }
+
+    /// 공결 횟수(승인된 결석신청 기간의 결석, 지각 포함)
+    pub fn excused(&self) -> u32 {
+        self.excused
+    }
+
+    /// 출결 미입력 횟수
+    pub fn unrecorded(&self) -> u32 {
+        self.unrecorded
+    }
+
+    /// 통과 기준에 반영되는 결석 횟수
+    ///
+    /// 수업별 출결 정보가 없다면 유세인트의 결석일수를 그대로 사용합니다.
+    pub fn effective_absences(&self) -> u32 {
+        self.effective_absences
+    }
+
+    /// 남은 결석 가능 횟수
+    pub fn remaining_absences(&self) -> u32 {
+        self.remaining_absences
+    }
+
+    /// 통과 예상 결과
+    pub fn prediction(&self) -> ChapelPrediction {
+        self.prediction
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ChapelPolicy, ChapelPrediction};
+    use crate::{
+        application::{
+            chapel::model::{
+                ChapelAbsenceRequest, ChapelAttendance, ChapelInformation, GeneralChapelInformation,
+            },
+            utils::from_fields,
+        },
+        model::SemesterType,
+    };
+
+    fn attendance(date: &str, status: &str) -> ChapelAttendance {
+        from_fields(&[
+            ("분반", "1"),
+            ("수업일자", date),
+            ("강의구분", "설교"),
+            ("강사", "홍길동"),
+            ("소속", "교목실"),
+            ("제목", "채플"),
+            ("출결상태", status),
+            ("평가", ""),
+            ("비고", ""),
+        ])
+    }
+
+    fn information(attendances: Vec<ChapelAttendance>, status: &str) -> ChapelInformation {
+        let general: GeneralChapelInformation = from_fields(&[
+            ("분반", "1"),
+            ("시간표", "화 10:30-11:20"),
+            ("강의실", "한경직기념관"),
+            ("층수", "1"),
+            ("좌석번호", "A-1"),
+            ("결석일수", "0"),
+            ("성적", ""),
+            ("비고", ""),
+        ]);
+        let request: ChapelAbsenceRequest = from_fields(&[
+            ("학년도", "2025"),
+            ("학기", "1 학기"),
+            ("결석구분상세", "질병"),
+            ("결석시작일자", "2025.03.18"),
+            ("결석종료일자", "2025.03.18"),
+            ("결석사유(국문)", "병원 진료"),
+            ("결석사유(영문)", ""),
+            ("신청일자", "2025.03.19"),
+            ("승인일자", "2025.03.20"),
+            ("거부사유", ""),
+            ("상태", status),
+        ]);
+        ChapelInformation::new(2025, SemesterType::One, general, attendances, vec![request])
+    }
+
+    #[test]
+    fn summarize_attendances() {
+        let attendances = vec![
+            attendance("2025.03.04", "출석"),
+            attendance("2025.03.11", "지각"),
+            attendance("2025.03.18", "결석"),
+            attendance("2025.03.25", "결석"),
+            attendance("2025.04.01", "지각"),
+            attendance("2025.04.08", ""),
+        ];
+        let summary = information(attendances.clone(), "승인").summary(&ChapelPolicy::default());
+        assert_eq!(summary.attended(), 1);
+        assert_eq!(summary.absent(), 1);
+        assert_eq!(summary.late(), 2);
+        assert_eq!(summary.excused(), 1);
+        assert_eq!(summary.unrecorded(), 1);
+        assert_eq!(summary.effective_absences(), 2);
+        assert_eq!(summary.remaining_absences(), 1);
+        assert_eq!(summary.prediction(), ChapelPrediction::LikelyPass);
+
+        let summary = information(attendances, "신청").summary(&ChapelPolicy::new(2, 2));
+        assert_eq!(summary.effective_absences(), 3);
+        assert_eq!(summary.prediction(), ChapelPrediction::LikelyFail);
+    }
+}
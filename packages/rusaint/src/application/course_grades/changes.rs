@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::course_grades::model::{ClassGrade, LetterGrade};
+
+/// 이전 조회와 현재 조회에서 성적이 달라진 과목
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ClassGradeUpdate {
+    /// 이전 성적
+    before: ClassGrade,
+    /// 현재 성적
+    after: ClassGrade,
+}
+
+impl ClassGradeUpdate {
+    /// 이전 성적
+    pub fn before(&self) -> &ClassGrade {
+        &self.before
+    }
+
+    /// 현재 성적
+    pub fn after(&self) -> &ClassGrade {
+        &self.after
+    }
+}
+
+/// 한 학기의 이전 성적 조회 결과와 현재 조회 결과를 비교한 변경 사항
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ClassGradeChanges {
+    /// 새로 성적이 입력된 과목
+    posted: Vec<ClassGrade>,
+    /// 성적이 변경된 과목
+    changed: Vec<ClassGradeUpdate>,
+    /// 상세성적을 새로 확인할 수 있게 된 과목
+    details_posted: Vec<ClassGrade>,
+    /// 현재 조회 결과, 다음 비교의 기준으로 저장하여 사용합니다.
+    current: Vec<ClassGrade>,
+}
+
+impl ClassGradeChanges {
+    /// 이전 조회 결과와 현재 조회 결과를 과목코드 기준으로 비교합니다.
+    ///
+    /// 이전에 없던 과목이나 성적이 비어 있던 과목에 성적이 입력되면 새로 입력된 성적으로,
+    /// 이미 입력된 성적의 점수나 등급이 바뀌면 변경된 성적으로 취급합니다.
+    pub fn between(previous: &[ClassGrade], current: Vec<ClassGrade>) -> Self {
+        let previous: HashMap<&str, &ClassGrade> =
+            previous.iter().map(|class| (class.code(), class)).collect();
+        let mut changes = ClassGradeChanges::default();
+        for class in &current {
+            let before = previous.get(class.code()).copied();
+            let was_posted = before.is_some_and(is_posted);
+            if is_posted(class) {
+                match before {
+                    Some(before) if was_posted => {
+                        if before.rank() != class.rank() || before.score() != class.score() {
+                            changes.changed.push(ClassGradeUpdate {
+                                before: before.clone(),
+                                after: class.clone(),
+                            });
+                        }
+                    }
+                    _ => changes.posted.push(class.clone()),
+                }
+            }
+            let had_detail = before.is_some_and(|before| before.breakdown().is_some());
+            if class.breakdown().is_some() && !had_detail {
+                changes.details_posted.push(class.clone());
+            }
+        }
+        changes.current = current;
+        changes
+    }
+
+    /// 새로 성적이 입력된 과목
+    pub fn posted(&self) -> &[ClassGrade] {
+        &self.posted
+    }
+
+    /// 성적이 변경된 과목
+    pub fn changed(&self) -> &[ClassGradeUpdate] {
+        &self.changed
+    }
+
+    /// 상세성적을 새로 확인할 수 있게 된 과목
+    pub fn details_posted(&self) -> &[ClassGrade] {
+        &self.details_posted
+    }
+
+    /// 현재 조회 결과
+    pub fn current(&self) -> &[ClassGrade] {
+        &self.current
+    }
+
+    /// 변경 사항이 없는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.posted.is_empty() && self.changed.is_empty() && self.details_posted.is_empty()
+    }
+}
+
+fn is_posted(class: &ClassGrade) -> bool {
+    !matches!(class.letter_grade(), LetterGrade::Empty)
+}
+
+#[cfg(test)]
+mod test {
+    use super::ClassGradeChanges;
+    use crate::{
+        application::course_grades::model::{
+            ClassGrade, ClassScore, GradeBreakdown, GradeComponent,
+        },
+        model::SemesterType,
+    };
+
+    fn class(code: &str, score: ClassScore, rank: &str, detail: bool) -> ClassGrade {
+        let breakdown = detail.then(|| {
+            GradeBreakdown::new(vec![GradeComponent::new("중간고사(30.000)", Some(30.0))])
+        });
+        ClassGrade::new(
+            2025,
+            SemesterType::One,
+            code.to_string(),
+            code.to_string(),
+            3.0,
+            score,
+            rank.to_string(),
+            "홍길동".to_string(),
+            breakdown,
+        )
+    }
+
+    #[test]
+    fn detect_changes() {
+        let previous = vec![
+            class("A", ClassScore::Empty, "", false),
+            class("B", ClassScore::Score(90), "A0", false),
+            class("C", ClassScore::Score(80), "B0", false),
+        ];
+        let current = vec![
+            class("A", ClassScore::Score(95), "A+", false),
+            class("B", ClassScore::Score(96), "A+", false),
+            class("C", ClassScore::Score(80), "B0", true),
+            class("D", ClassScore::Pass, "P", false),
+        ];
+        let changes = ClassGradeChanges::between(&previous, current);
+        let codes = |classes: &[ClassGrade]| -> Vec<String> {
+            classes
+                .iter()
+                .map(|class| class.code().to_string())
+                .collect()
+        };
+        assert_eq!(codes(changes.posted()), vec!["A", "D"]);
+        assert_eq!(changes.changed().len(), 1);
+        assert_eq!(changes.changed()[0].before().rank(), "A0");
+        assert_eq!(codes(changes.details_posted()), vec!["C"]);
+        assert_eq!(changes.current().len(), 4);
+        let unchanged = ClassGradeChanges::between(changes.current(), changes.current().to_vec());
+        assert!(unchanged.is_empty());
+    }
+}
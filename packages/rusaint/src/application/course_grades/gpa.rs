@@ -0,0 +1,426 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::{
+    course_grades::model::{ClassGrade, ClassScore},
+    course_schedule::model::Lecture,
+    graduation_requirements::model::GraduationRequirements,
+};
+
+/// 과목의 이수구분
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum CourseCategory {
+    /// 전공필수
+    MajorRequired,
+    /// 전공선택
+    MajorElective,
+    /// 전공기초
+    MajorFoundation,
+    /// 교양필수
+    LiberalRequired,
+    /// 교양선택
+    LiberalElective,
+    /// 채플
+    Chapel,
+    /// 기타 이수구분
+    Other(String),
+}
+
+impl CourseCategory {
+    /// `전필-컴퓨터`, `교선` 등 유세인트에 표시되는 이수구분 문자열을 이수구분으로 변환합니다.
+    pub fn from_category(category: &str) -> CourseCategory {
+        let category = category.trim();
+        let prefix = category.split(['-', '(', ' ']).next().unwrap_or_default();
+        match prefix {
+            "전필" | "전공필수" => CourseCategory::MajorRequired,
+            "전선" | "전공선택" => CourseCategory::MajorElective,
+            "전기" | "전공기초" => CourseCategory::MajorFoundation,
+            "교필" | "교양필수" => CourseCategory::LiberalRequired,
+            "교선" | "교양선택" => CourseCategory::LiberalElective,
+            "채플" => CourseCategory::Chapel,
+            _ => CourseCategory::Other(category.to_string()),
+        }
+    }
+
+    /// 전공평점 계산에 포함되는 이수구분인지 여부를 반환합니다.
+    pub fn is_major(&self) -> bool {
+        matches!(
+            self,
+            CourseCategory::MajorRequired
+                | CourseCategory::MajorElective
+                | CourseCategory::MajorFoundation
+        )
+    }
+}
+
+/// 평점 계산 결과
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct Gpa {
+    /// 평점 계산에 포함된 학점
+    credits: f32,
+    /// 평점계
+    grade_points_sum: f32,
+}
+
+impl Gpa {
+    pub(crate) fn new(credits: f32, grade_points_sum: f32) -> Self {
+        Self {
+            credits,
+            grade_points_sum,
+        }
+    }
+
+    pub(crate) fn add(&mut self, credits: f32, grade_point: f32) {
+        self.credits += credits;
+        self.grade_points_sum += credits * grade_point;
+    }
+
+    /// 평점 계산에 포함된 학점
+    pub fn credits(&self) -> f32 {
+        self.credits
+    }
+
+    /// 평점계
+    pub fn grade_points_sum(&self) -> f32 {
+        self.grade_points_sum
+    }
+
+    /// 평점평균, 평점 계산에 포함된 과목이 없다면 `0.0`
+    pub fn average(&self) -> f32 {
+        if self.credits == 0.0 {
+            0.0
+        } else {
+            self.grade_points_sum / self.credits
+        }
+    }
+}
+
+/// 이수구분별 평점
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct CategoryGpa {
+    /// 이수구분
+    category: CourseCategory,
+    /// 평점
+    gpa: Gpa,
+}
+
+impl CategoryGpa {
+    /// 이수구분
+    pub fn category(&self) -> &CourseCategory {
+        &self.category
+    }
+
+    /// 평점
+    pub fn gpa(&self) -> Gpa {
+        self.gpa
+    }
+}
+
+/// 전체, 전공, 이수구분별 평점 계산 결과
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct GpaReport {
+    /// 전체 평점
+    overall: Gpa,
+    /// 전공평점
+    major: Gpa,
+    /// 이수구분별 평점
+    categories: Vec<CategoryGpa>,
+    /// 이수구분을 알 수 없어 이수구분별 평점에서 제외된 과목코드
+    uncategorized: Vec<String>,
+}
+
+impl GpaReport {
+    /// 전체 평점
+    pub fn overall(&self) -> Gpa {
+        self.overall
+    }
+
+    /// 전공(전필, 전선, 전기) 평점
+    pub fn major(&self) -> Gpa {
+        self.major
+    }
+
+    /// 이수구분별 평점
+    pub fn categories(&self) -> &[CategoryGpa] {
+        &self.categories
+    }
+
+    /// 주어진 이수구분의 평점
+    pub fn category(&self, category: &CourseCategory) -> Option<Gpa> {
+        self.categories
+            .iter()
+            .find(|gpa| &gpa.category == category)
+            .map(CategoryGpa::gpa)
+    }
+
+    /// 이수구분을 알 수 없어 이수구분별 평점에서 제외된 과목코드
+    pub fn uncategorized(&self) -> &[String] {
+        &self.uncategorized
+    }
+}
+
+/// 과목별 성적과 이수구분으로 전공평점 및 이수구분별 평점을 계산하는 계산기
+///
+/// 숭실대학교 학칙에 따라 다음과 같이 계산합니다.
+/// - P/F 과목과 성적이 없는 과목(철회 등)은 평점 계산에서 제외합니다.
+/// - F 등급은 0점으로 평점 계산에 포함합니다.
+/// - 같은 과목을 재수강한 경우 가장 마지막 수강 성적만 포함합니다.
+///
+/// ### 예시
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// # use std::sync::Arc;
+/// # use rusaint::USaintSession;
+/// # use rusaint::application::USaintClientBuilder;
+/// # use rusaint::application::course_grades::{CourseGradesApplication, gpa::GpaCalculator, model::CourseType};
+/// # use rusaint::application::graduation_requirements::GraduationRequirementsApplication;
+/// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+/// let mut app = USaintClientBuilder::new().session(session.clone()).build_into::<CourseGradesApplication>().await.unwrap();
+/// let grades = app.all_classes(CourseType::Bachelor, false).await.unwrap();
+/// let mut requirements_app = USaintClientBuilder::new().session(session).build_into::<GraduationRequirementsApplication>().await.unwrap();
+/// let requirements = requirements_app.requirements().await.unwrap();
+/// let report = GpaCalculator::new(grades.classes().cloned())
+///     .categories_from_requirements(&requirements)
+///     .calculate();
+/// println!("{}", report.major().average());
+/// # })
+/// ```
+#[derive(Clone, Debug)]
+pub struct GpaCalculator {
+    classes: Vec<ClassGrade>,
+    categories: HashMap<String, CourseCategory>,
+    categories_by_name: HashMap<String, CourseCategory>,
+}
+
+impl GpaCalculator {
+    /// 과목별 성적으로 새로운 계산기를 만듭니다.
+    pub fn new(classes: impl IntoIterator<Item = ClassGrade>) -> Self {
+        Self {
+            classes: classes.into_iter().collect(),
+            categories: HashMap::new(),
+            categories_by_name: HashMap::new(),
+        }
+    }
+
+    /// 과목코드의 이수구분을 지정합니다.
+    pub fn category(mut self, code: &str, category: CourseCategory) -> Self {
+        self.categories.insert(code.to_string(), category);
+        self
+    }
+
+    /// 강의시간표에서 가져온 강의 목록의 이수구분(주전공)으로 과목의 이수구분을 지정합니다.
+    pub fn categories_from_lectures<'a>(
+        mut self,
+        lectures: impl IntoIterator<Item = &'a Lecture>,
+    ) -> Self {
+        for lecture in lectures {
+            self.categories.insert(
+                lecture.code().to_string(),
+                CourseCategory::from_category(lecture.category()),
+            );
+        }
+        self
+    }
+
+    /// 졸업사정표의 졸업요건별 과목 목록으로 과목의 이수구분을 지정합니다.
+    ///
+    /// 졸업요건의 과목 목록은 과목명으로 표시되므로, 과목코드로 지정된 이수구분이 없는 과목에만 과목명으로 적용됩니다.
+    pub fn categories_from_requirements(mut self, requirements: &GraduationRequirements) -> Self {
+        for requirement in requirements.requirements().values() {
+            let category = CourseCategory::from_category(requirement.category());
+            if matches!(category, CourseCategory::Other(_)) {
+                continue;
+            }
+            for lecture in requirement.lectures() {
+                let name = lecture.trim();
+                if !name.is_empty() {
+                    self.categories_by_name
+                        .insert(name.to_string(), category.clone());
+                }
+            }
+        }
+        self
+    }
+
+    fn category_of(&self, class: &ClassGrade) -> Option<&CourseCategory> {
+        self.categories
+            .get(class.code())
+            .or_else(|| self.categories_by_name.get(class.class_name().trim()))
+    }
+
+    /// 재수강을 반영하여 평점 계산에 포함되는 과목별 성적을 반환합니다.
+    fn effective_classes(&self) -> Vec<&ClassGrade> {
+        let mut latest: HashMap<&str, &ClassGrade> = HashMap::new();
+        for class in &self.classes {
+            latest
+                .entry(class.code())
+                .and_modify(|existing| {
+                    if (class.year(), class.semester()) >= (existing.year(), existing.semester()) {
+                        *existing = class;
+                    }
+                })
+                .or_insert(class);
+        }
+        let mut classes: Vec<&ClassGrade> = latest.into_values().collect();
+        classes.sort_by_key(|class| (class.year(), class.semester(), class.code().to_string()));
+        classes
+    }
+
+    /// 평점을 계산합니다.
+    pub fn calculate(&self) -> GpaReport {
+        let mut report = GpaReport::default();
+        for class in self.effective_classes() {
+            let Some(grade_point) = grade_point(class) else {
+                continue;
+            };
+            let credits = class.grade_points();
+            report.overall.add(credits, grade_point);
+            let Some(category) = self.category_of(class) else {
+                report.uncategorized.push(class.code().to_string());
+                continue;
+            };
+            if category.is_major() {
+                report.major.add(credits, grade_point);
+            }
+            match report
+                .categories
+                .iter_mut()
+                .find(|gpa| &gpa.category == category)
+            {
+                Some(gpa) => gpa.gpa.add(credits, grade_point),
+                None => {
+                    let mut gpa = Gpa::default();
+                    gpa.add(credits, grade_point);
+                    report.categories.push(CategoryGpa {
+                        category: category.clone(),
+                        gpa,
+                    });
+                }
+            }
+        }
+        report
+    }
+}
+
+/// 평점 계산에 포함되는 과목의 등급별 평점을 반환합니다. P/F 과목 및 성적이 없는 과목은 `None`을 반환합니다.
+fn grade_point(class: &ClassGrade) -> Option<f32> {
+    if !matches!(class.score(), ClassScore::Score(_)) {
+        return None;
+    }
+    class.letter_grade().grade_point()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CourseCategory, GpaCalculator};
+    use crate::{
+        application::course_grades::model::{ClassGrade, ClassScore},
+        model::SemesterType,
+    };
+
+    fn class(
+        year: u32,
+        semester: SemesterType,
+        code: &str,
+        credits: f32,
+        score: ClassScore,
+        rank: &str,
+    ) -> ClassGrade {
+        ClassGrade::new(
+            year,
+            semester,
+            code.to_string(),
+            code.to_string(),
+            credits,
+            score,
+            rank.to_string(),
+            "홍길동".to_string(),
+            None,
+        )
+    }
+
+    #[test]
+    fn category_from_string() {
+        assert_eq!(
+            CourseCategory::from_category("전필-컴퓨터"),
+            CourseCategory::MajorRequired
+        );
+        assert_eq!(
+            CourseCategory::from_category("교선"),
+            CourseCategory::LiberalElective
+        );
+        assert_eq!(
+            CourseCategory::from_category("일선"),
+            CourseCategory::Other("일선".to_string())
+        );
+    }
+
+    #[test]
+    fn calculate_gpa() {
+        let classes = vec![
+            class(
+                2022,
+                SemesterType::One,
+                "A",
+                3.0,
+                ClassScore::Score(60),
+                "F",
+            ),
+            class(
+                2023,
+                SemesterType::One,
+                "A",
+                3.0,
+                ClassScore::Score(95),
+                "A+",
+            ),
+            class(
+                2022,
+                SemesterType::One,
+                "B",
+                3.0,
+                ClassScore::Score(85),
+                "B+",
+            ),
+            class(
+                2022,
+                SemesterType::Two,
+                "C",
+                2.0,
+                ClassScore::Score(70),
+                "C0",
+            ),
+            class(2022, SemesterType::Two, "D", 1.0, ClassScore::Pass, "P"),
+            class(
+                2022,
+                SemesterType::Two,
+                "E",
+                3.0,
+                ClassScore::Score(50),
+                "F",
+            ),
+        ];
+        let report = GpaCalculator::new(classes)
+            .category("A", CourseCategory::MajorRequired)
+            .category("B", CourseCategory::MajorElective)
+            .category("C", CourseCategory::LiberalElective)
+            .category("E", CourseCategory::MajorElective)
+            .calculate();
+        assert_eq!(report.overall().credits(), 11.0);
+        assert_eq!(report.major().credits(), 9.0);
+        assert!((report.major().average() - 8.0 / 3.0).abs() < 1e-5);
+        assert_eq!(
+            report
+                .category(&CourseCategory::MajorRequired)
+                .unwrap()
+                .average(),
+            4.5
+        );
+        assert!(report.uncategorized().is_empty());
+    }
+}
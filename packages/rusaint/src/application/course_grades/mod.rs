@@ -1,6 +1,14 @@
-use self::model::{ClassGrade, CourseType, GradeSummary, SemesterGrade};
+use self::changes::ClassGradeChanges;
+use self::model::{
+    AcademicHistory, AllClassGrades, ClassGrade, CourseType, GradeBreakdown, GradeComponent,
+    GradeSummary, ProgramGrades, ProgramGradesFailure, SemesterClassGrades,
+    SemesterClassGradesFailure, SemesterGrade,
+};
+use self::reconciliation::SummaryReconciliation;
+use self::standing::{AcademicStanding, StandingPolicy};
 use super::{USaintApplication, USaintClient};
 use crate::application::utils::input_field::InputFieldExt as _;
+use crate::application::utils::popup::close_popups;
 use crate::application::utils::sap_table::try_table_into_with_scroll;
 use crate::application::utils::semester::get_selected_semester;
 use crate::{RusaintError, model::SemesterType};
@@ -13,15 +21,17 @@ use wdpe::element::parser::ElementParser;
 use wdpe::{
     command::element::{
         complex::SapTableBodyCommand,
-        selection::{ComboBoxLSDataCommand, ComboBoxSelectEventCommand},
+        selection::{
+            ComboBoxItemListBoxCommand, ComboBoxLSDataCommand, ComboBoxSelectEventCommand,
+            ListBoxItemInfoCommand,
+        },
     },
     define_elements,
     element::{
         Element, ElementDefWrapper, ElementWrapper,
         complex::sap_table::{SapTable, cell::SapTableCell},
         definition::ElementDefinition,
-        layout::PopupWindow,
-        selection::ComboBox,
+        selection::{ComboBox, list_box::item::ListBoxItemInfo},
         text::InputField,
     },
     error::{BodyError, ElementError, WebDynproError},
@@ -87,27 +97,6 @@ impl<'a> CourseGradesApplication {
         GRADE_BY_CLASSES_TABLE: SapTable<'a> = "ZCMB3W0017.ID_0001:VIW_MAIN.TABLE_1";
     );
 
-    async fn close_popups(&mut self) -> Result<(), WebDynproError> {
-        let popup_selector =
-            Selector::parse(format!(r#"[ct="{}"]"#, PopupWindow::CONTROL_ID).as_str()).unwrap();
-        fn make_close_event(body: &Body, selector: &Selector) -> Option<Event> {
-            let parser = ElementParser::new(body);
-            let mut popup_iter = parser.document().select(selector);
-            popup_iter.next().and_then(|elem| {
-                let elem_wrapped = ElementWrapper::from_ref(elem).ok()?;
-                if let ElementWrapper::PopupWindow(popup) = elem_wrapped {
-                    popup.close().ok()
-                } else {
-                    None
-                }
-            })
-        }
-        while let Some(event) = make_close_event(self.body(), &popup_selector) {
-            self.client.process_event(false, event).await?;
-        }
-        Ok(())
-    }
-
     fn semester_to_key(semester: SemesterType) -> &'static str {
         match semester {
             SemesterType::One => "090",
@@ -127,6 +116,29 @@ impl<'a> CourseGradesApplication {
         }
     }
 
+    /// 학위과정 선택 목록에 있는 학위과정을 반환합니다. 성적 정보가 없는 학위과정도 포함될 수 있습니다.
+    fn listed_course_types(&self) -> Result<Vec<CourseType>, RusaintError> {
+        const COURSE_TYPES: [CourseType; 5] = [
+            CourseType::Phd,
+            CourseType::Master,
+            CourseType::PhdIntergrated,
+            CourseType::Research,
+            CourseType::Bachelor,
+        ];
+        let parser = ElementParser::new(self.client.body());
+        let item_box = parser.read(ComboBoxItemListBoxCommand::new(Self::PROGRESS_TYPE))?;
+        let items = parser.read(ListBoxItemInfoCommand::new(item_box))?;
+        Ok(items
+            .iter()
+            .filter_map(|item| match item {
+                ListBoxItemInfo::Item { key, .. } => COURSE_TYPES
+                    .into_iter()
+                    .find(|course_type| Self::course_type_to_key(*course_type) == key),
+                ListBoxItemInfo::ActionItem { .. } => None,
+            })
+            .collect())
+    }
+
     async fn select_course(
         &mut self,
         parser: &ElementParser,
@@ -205,7 +217,7 @@ impl<'a> CourseGradesApplication {
         &mut self,
         course_type: CourseType,
     ) -> Result<GradeSummary, RusaintError> {
-        self.close_popups().await?;
+        close_popups(&mut self.client).await?;
         let parser = ElementParser::new(self.client.body());
         self.select_course(&parser, course_type).await?;
         self.read_recorded_summary()
@@ -254,7 +266,7 @@ impl<'a> CourseGradesApplication {
         &mut self,
         course_type: CourseType,
     ) -> Result<GradeSummary, RusaintError> {
-        self.close_popups().await?;
+        close_popups(&mut self.client).await?;
         let parser = ElementParser::new(self.client.body());
         self.select_course(&parser, course_type).await?;
         self.read_certificated_summary()
@@ -303,12 +315,113 @@ impl<'a> CourseGradesApplication {
         &mut self,
         course_type: CourseType,
     ) -> Result<Vec<SemesterGrade>, RusaintError> {
-        self.close_popups().await?;
+        close_popups(&mut self.client).await?;
         let parser = ElementParser::new(self.client.body());
         self.select_course(&parser, course_type).await?;
         self.read_semesters().await
     }
 
+    /// 학생의 성적 정보가 있는 학위과정 목록을 가져옵니다.
+    ///
+    /// 학위과정 선택 목록의 각 학위과정을 선택하여 학기별 성적이 있는 학위과정만 반환합니다.
+    /// ### 예시
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// # use std::sync::Arc;
+    /// # use rusaint::USaintSession;
+    /// # use rusaint::application::course_grades::CourseGradesApplication;
+    /// # use rusaint::application::USaintClientBuilder;
+    /// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+    /// let mut app = USaintClientBuilder::new().session(session).build_into::<CourseGradesApplication>().await.unwrap();
+    /// let course_types = app.course_types().await.unwrap();
+    /// println!("{:?}", course_types);
+    /// // [Bachelor]
+    /// # })
+    /// ```
+    pub async fn course_types(&mut self) -> Result<Vec<CourseType>, RusaintError> {
+        close_popups(&mut self.client).await?;
+        let mut course_types = Vec::new();
+        for course_type in self.listed_course_types()? {
+            if !self.semesters(course_type).await?.is_empty() {
+                course_types.push(course_type);
+            }
+        }
+        Ok(course_types)
+    }
+
+    /// 성적 정보가 있는 모든 학위과정의 전체 성적과 학기별 성적을 가져옵니다.
+    /// 학사과정에서 석사과정으로 진학한 경우와 같이 여러 학위과정을 이수한 학생의 성적을 한번에 확인할 수 있습니다.
+    ///
+    /// 학기별 성적이 없는 학위과정은 결과에서 제외되며, 성적을 가져오지 못한 학위과정은 [`AcademicHistory::failures()`]에 기록됩니다.
+    /// ### 예시
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// # use std::sync::Arc;
+    /// # use rusaint::USaintSession;
+    /// # use rusaint::application::course_grades::CourseGradesApplication;
+    /// # use rusaint::application::USaintClientBuilder;
+    /// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+    /// let mut app = USaintClientBuilder::new().session(session).build_into::<CourseGradesApplication>().await.unwrap();
+    /// let history = app.academic_history().await.unwrap();
+    /// for (course_type, semester) in history.semesters() {
+    ///     println!("{:?} {} {:?}", course_type, semester.year(), semester.semester());
+    /// }
+    /// println!("{:?}", history.failures());
+    /// # })
+    /// ```
+    pub async fn academic_history(&mut self) -> Result<AcademicHistory, RusaintError> {
+        close_popups(&mut self.client).await?;
+        let course_types = self.listed_course_types()?;
+        let mut programs = Vec::with_capacity(course_types.len());
+        let mut failures = Vec::new();
+        for course_type in course_types {
+            match self.program_grades(course_type).await {
+                Ok(program) if program.semesters().is_empty() => {}
+                Ok(program) => programs.push(program),
+                Err(err) => failures.push(ProgramGradesFailure::new(course_type, err.to_string())),
+            }
+        }
+        Ok(AcademicHistory::new(programs, failures))
+    }
+
+    async fn program_grades(
+        &mut self,
+        course_type: CourseType,
+    ) -> Result<ProgramGrades, RusaintError> {
+        let recorded_summary = self.recorded_summary(course_type).await?;
+        let certificated_summary = self.certificated_summary(course_type).await?;
+        let semesters = self.semesters(course_type).await?;
+        Ok(ProgramGrades::new(
+            course_type,
+            recorded_summary,
+            certificated_summary,
+            semesters,
+        ))
+    }
+
+    /// 학기별 성적을 주어진 학사경고 및 제적 기준으로 분석하여 학사경고 이력, 석차 백분위, 평점 추세 및 제적 위험도를 반환합니다.
+    /// ### 예시
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// # use std::sync::Arc;
+    /// # use rusaint::USaintSession;
+    /// # use rusaint::application::course_grades::{ model::CourseType, standing::StandingPolicy, CourseGradesApplication };
+    /// # use rusaint::application::USaintClientBuilder;
+    /// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+    /// let mut app = USaintClientBuilder::new().session(session).build_into::<CourseGradesApplication>().await.unwrap();
+    /// let standing = app.academic_standing(CourseType::Bachelor, &StandingPolicy::default()).await.unwrap();
+    /// println!("{:?}", standing.risk());
+    /// # })
+    /// ```
+    pub async fn academic_standing(
+        &mut self,
+        course_type: CourseType,
+        policy: &StandingPolicy,
+    ) -> Result<AcademicStanding, RusaintError> {
+        let semesters = self.semesters(course_type).await?;
+        Ok(AcademicStanding::analyze(&semesters, policy))
+    }
+
     async fn read_semesters(&mut self) -> Result<Vec<SemesterGrade>, RusaintError> {
         let parser = ElementParser::new(self.client.body());
         let ret = try_table_into_with_scroll::<SemesterGrade>(
@@ -323,10 +436,10 @@ impl<'a> CourseGradesApplication {
     async fn class_detail_in_popup(
         &mut self,
         press_event: Event,
-    ) -> Result<HashMap<String, f32>, RusaintError> {
+    ) -> Result<GradeBreakdown, RusaintError> {
         self.client.process_event(false, press_event).await?;
 
-        let parse_table_in_popup = |body: &Body| -> Result<HashMap<String, f32>, WebDynproError> {
+        let parse_table_in_popup = |body: &Body| -> Result<GradeBreakdown, WebDynproError> {
             let table_inside_popup_selector = Selector::parse(r#"[ct="PW"] [ct="ST"]"#).unwrap();
             let parser = ElementParser::new(body);
             let mut table_inside_popup = parser.document().select(&table_inside_popup_selector);
@@ -344,23 +457,25 @@ impl<'a> CourseGradesApplication {
                 })?
                 .try_row_into::<Vec<(String, String)>>(table_body.header(), &parser)?
                 .into_iter();
-            zip.skip(4)
+            let components = zip
+                .skip(4)
                 .map(|(key, val)| {
                     let str = val.trim();
                     if str.is_empty() {
-                        return Ok((key, -1.0));
+                        return Ok(GradeComponent::new(&key, None));
                     }
                     let float = str.parse::<f32>().or(Err(ElementError::InvalidContent {
                         element: format!("TABLE: {}, key: {}", table_elem.id(), key),
                         content: "(not an correct f32)".to_string(),
                     }))?;
-                    Ok((key, float))
+                    Ok(GradeComponent::new(&key, Some(float)))
                 })
-                .collect::<Result<HashMap<String, f32>, WebDynproError>>()
+                .collect::<Result<Vec<GradeComponent>, WebDynproError>>()?;
+            Ok(GradeBreakdown::new(components))
         };
         let table = parse_table_in_popup(self.client.body())?;
-        self.close_popups().await?;
-        Ok(HashMap::from_iter(table))
+        close_popups(&mut self.client).await?;
+        Ok(table)
     }
 
     /// 주어진 학기의 수업별 성적을 가져옵니다. `include_details`가 `true`인 경우 수업의 상세 성적도 가져옵니다.
@@ -406,7 +521,7 @@ impl<'a> CourseGradesApplication {
         include_details: bool,
     ) -> Result<Vec<ClassGrade>, RusaintError> {
         {
-            self.close_popups().await?;
+            close_popups(&mut self.client).await?;
             let parser = ElementParser::new(self.client.body());
             self.select_course(&parser, course_type).await?;
             self.select_semester(&parser, &year.to_string(), semester)
@@ -438,7 +553,7 @@ impl<'a> CourseGradesApplication {
         };
         let mut ret: Vec<ClassGrade> = vec![];
         for (btn_event, values) in class_grades {
-            let detail: Option<HashMap<String, f32>> = if let Some(btn_event) = btn_event {
+            let detail: Option<GradeBreakdown> = if let Some(btn_event) = btn_event {
                 if include_details {
                     Some(self.class_detail_in_popup(btn_event).await?)
                 } else {
@@ -467,6 +582,115 @@ impl<'a> CourseGradesApplication {
         Ok(ret)
     }
 
+    /// [`semesters()`](Self::semesters)에 포함된 모든 학기(계절학기 포함)의 수업별 성적을 한번에 가져옵니다.
+    /// `include_details`가 `true`인 경우 수업의 상세 성적도 가져옵니다.
+    ///
+    /// 특정 학기의 성적을 가져오는 데 실패하더라도 나머지 학기의 성적은 계속 가져오며, 실패한 학기는 [`AllClassGrades::failures()`]로 확인할 수 있습니다.
+    /// ### 예시
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// # use std::sync::Arc;
+    /// # use rusaint::USaintSession;
+    /// # use rusaint::application::course_grades::{ model::CourseType, CourseGradesApplication };
+    /// # use rusaint::application::USaintClientBuilder;
+    /// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+    /// let mut app = USaintClientBuilder::new().session(session).build_into::<CourseGradesApplication>().await.unwrap();
+    /// let grades = app.all_classes(CourseType::Bachelor, false).await.unwrap();
+    /// for semester in grades.semesters() {
+    ///     println!("{} {}: {:?}", semester.year(), semester.semester(), semester.classes());
+    /// }
+    /// println!("{:?}", grades.failures());
+    /// # })
+    /// ```
+    pub async fn all_classes(
+        &mut self,
+        course_type: CourseType,
+        include_details: bool,
+    ) -> Result<AllClassGrades, RusaintError> {
+        let semesters = self.semesters(course_type).await?;
+        let mut grades = Vec::with_capacity(semesters.len());
+        let mut failures = Vec::new();
+        for semester in semesters {
+            let (year, semester) = (semester.year(), semester.semester());
+            match self
+                .classes(course_type, year, semester, include_details)
+                .await
+            {
+                Ok(classes) => grades.push(SemesterClassGrades::new(year, semester, classes)),
+                Err(err) => failures.push(SemesterClassGradesFailure::new(
+                    year,
+                    semester,
+                    err.to_string(),
+                )),
+            }
+        }
+        Ok(AllClassGrades::new(grades, failures))
+    }
+
+    /// 학적부 성적과 증명 성적을 가져와, 전체 학기의 수업별 성적으로 두 성적의 차이를 과목별로 설명합니다.
+    /// ### 예시
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// # use std::sync::Arc;
+    /// # use rusaint::USaintSession;
+    /// # use rusaint::application::course_grades::{ model::CourseType, CourseGradesApplication };
+    /// # use rusaint::application::USaintClientBuilder;
+    /// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+    /// let mut app = USaintClientBuilder::new().session(session).build_into::<CourseGradesApplication>().await.unwrap();
+    /// let reconciliation = app.summary_reconciliation(CourseType::Bachelor).await.unwrap();
+    /// println!("{}", reconciliation.attempted_credits().difference());
+    /// // 3.0
+    /// # })
+    /// ```
+    pub async fn summary_reconciliation(
+        &mut self,
+        course_type: CourseType,
+    ) -> Result<SummaryReconciliation, RusaintError> {
+        let recorded = self.recorded_summary(course_type).await?;
+        let certificated = self.certificated_summary(course_type).await?;
+        let classes = self.all_classes(course_type, false).await?;
+        Ok(SummaryReconciliation::new(
+            &recorded,
+            &certificated,
+            classes.classes(),
+        ))
+    }
+
+    /// 주어진 학기의 수업별 성적을 다시 가져와 이전에 저장한 조회 결과(`previous`)와 비교합니다.
+    ///
+    /// 새로 입력된 성적, 변경된 성적, 새로 확인할 수 있게 된 상세 성적을 반환하며, 반환값의 [`ClassGradeChanges::current()`]를 저장하여 다음 비교에 사용할 수 있습니다.
+    /// 상세 성적의 변경 사항은 `include_details`가 `true`인 경우에만 확인할 수 있습니다.
+    /// ### 예시
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// # use std::sync::Arc;
+    /// # use rusaint::USaintSession;
+    /// # use rusaint::application::course_grades::{ model::CourseType, CourseGradesApplication };
+    /// # use rusaint::application::USaintClientBuilder;
+    /// # use rusaint::model::SemesterType;
+    /// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+    /// let mut app = USaintClientBuilder::new().session(session).build_into::<CourseGradesApplication>().await.unwrap();
+    /// let previous = app.classes(CourseType::Bachelor, 2025, SemesterType::One, false).await.unwrap();
+    /// let changes = app.class_changes(CourseType::Bachelor, 2025, SemesterType::One, &previous, false).await.unwrap();
+    /// for class in changes.posted() {
+    ///     println!("{}의 성적이 입력되었습니다.", class.class_name());
+    /// }
+    /// # })
+    /// ```
+    pub async fn class_changes(
+        &mut self,
+        course_type: CourseType,
+        year: u32,
+        semester: SemesterType,
+        previous: &[ClassGrade],
+        include_details: bool,
+    ) -> Result<ClassGradeChanges, RusaintError> {
+        let current = self
+            .classes(course_type, year, semester, include_details)
+            .await?;
+        Ok(ClassGradeChanges::between(previous, current))
+    }
+
     /// 주어진 수업의 상세 성적 정보를 가져옵니다. 만약 상세 성적이 음수라면, 성적이 비어 있다는 의미입니다.
     /// ### 예시
     /// ```no_run
@@ -492,9 +716,42 @@ impl<'a> CourseGradesApplication {
         semester: SemesterType,
         code: &str,
     ) -> Result<HashMap<String, f32>, RusaintError> {
+        Ok(self
+            .class_breakdown(course_type, year, semester, code)
+            .await?
+            .raw())
+    }
+
+    /// 주어진 수업의 항목별 상세 성적 정보를 유세인트에 표시되는 순서대로 가져옵니다.
+    /// ### 예시
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// # use std::sync::Arc;
+    /// # use rusaint::USaintSession;
+    /// # use rusaint::model::SemesterType;
+    /// # use rusaint::application::course_grades::{ model::CourseType, CourseGradesApplication };
+    /// # use rusaint::application::USaintClientBuilder;
+    /// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+    /// let mut app = USaintClientBuilder::new().session(session).build_into::<CourseGradesApplication>().await.unwrap();
+    /// let classes = app.classes(CourseType::Bachelor, 2022, SemesterType::Two, false).await.unwrap();
+    /// let class = classes.iter().next().unwrap();
+    /// let breakdown = app.class_breakdown(CourseType::Bachelor, 2022, SemesterType::Two, class.code()).await.unwrap();
+    /// for component in breakdown.components() {
+    ///     println!("{}: {:?} / {:?}", component.name(), component.score(), component.weight());
+    /// }
+    /// println!("{}", breakdown.total());
+    /// # })
+    /// ```
+    pub async fn class_breakdown(
+        &mut self,
+        course_type: CourseType,
+        year: u32,
+        semester: SemesterType,
+        code: &str,
+    ) -> Result<GradeBreakdown, RusaintError> {
         let year = year.to_string();
         {
-            self.close_popups().await?;
+            close_popups(&mut self.client).await?;
             let parser = ElementParser::new(self.client.body());
             self.select_course(&parser, course_type).await?;
             self.select_semester(&parser, &year, semester).await?;
@@ -544,13 +801,25 @@ impl<'a> CourseGradesApplication {
     }
 }
 
+/// 이전 성적 조회 결과와 비교하여 새로 입력되거나 변경된 성적을 찾는 기능
+pub mod changes;
+/// 과목별 성적으로 전공평점 및 이수구분별 평점을 계산하는 계산기
+pub mod gpa;
 /// [`CourseGradesApplication`]에서 사용하는 데이터
 pub mod model;
+/// 학적부 성적과 증명 성적의 차이를 과목별로 설명하는 기능
+pub mod reconciliation;
+/// 가상 성적을 반영하여 학기 및 누적 평점을 예측하는 시뮬레이터
+pub mod simulator;
+/// 학기별 성적으로 학사경고 위험도, 석차 백분위, 평점 추세를 분석하는 기능
+pub mod standing;
+/// 학생 정보와 성적을 합친 성적표를 CSV, JSON, HTML 형식으로 내보내는 기능
+pub mod transcript;
 
 #[cfg(test)]
 mod test {
     use crate::{
-        application::{USaintClientBuilder, course_grades::CourseGradesApplication},
+        application::{USaintClientBuilder, course_grades::CourseGradesApplication, utils::popup},
         global_test_utils::get_session,
     };
     use wdpe::element::{Element, layout::PopupWindow, parser::ElementParser};
@@ -563,7 +832,7 @@ mod test {
             .build_into::<CourseGradesApplication>()
             .await
             .unwrap();
-        app.close_popups().await.unwrap();
+        popup::close_popups(&mut app.client).await.unwrap();
         let popup_selector =
             scraper::Selector::parse(format!(r#"[ct="{}"]"#, PopupWindow::CONTROL_ID).as_str())
                 .unwrap();
@@ -200,6 +200,11 @@ impl SemesterGrade {
         self.semester
     }
 
+    /// 신청학점
+    pub fn attempted_credits(&self) -> f32 {
+        self.attempted_credits
+    }
+
     /// 취득학점
     pub fn earned_credits(&self) -> f32 {
         self.earned_credits
@@ -269,7 +274,8 @@ impl<'body> FromSapTable<'body> for SemesterGrade {
 }
 
 /// 과목별 성적
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(from = "SerializedClassGrade")]
 #[allow(unused)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 pub struct ClassGrade {
@@ -287,10 +293,59 @@ pub struct ClassGrade {
     score: ClassScore,
     /// 등급
     rank: String,
+    /// 등급(열거형)
+    letter_grade: LetterGrade,
     /// 교수명
     professor: String,
     /// 상세성적
     detail: Option<HashMap<String, f32>>,
+    /// 항목별 상세성적
+    breakdown: Option<GradeBreakdown>,
+}
+
+/// 이전 버전에서 직렬화된 상세성적(`detail`)과 등급(열거형)이 없는 값도 읽을 수 있도록 하는 [`ClassGrade`]의 직렬화 형태
+#[derive(Deserialize)]
+struct SerializedClassGrade {
+    year: u32,
+    semester: SemesterType,
+    code: String,
+    class_name: String,
+    grade_points: f32,
+    score: ClassScore,
+    rank: String,
+    #[serde(default)]
+    letter_grade: Option<LetterGrade>,
+    professor: String,
+    #[serde(default)]
+    detail: Option<HashMap<String, f32>>,
+    #[serde(default)]
+    breakdown: Option<GradeBreakdown>,
+}
+
+impl From<SerializedClassGrade> for ClassGrade {
+    fn from(value: SerializedClassGrade) -> Self {
+        let breakdown = value
+            .breakdown
+            .or_else(|| value.detail.clone().map(GradeBreakdown::from_raw));
+        let detail = value
+            .detail
+            .or_else(|| breakdown.as_ref().map(GradeBreakdown::raw));
+        ClassGrade {
+            year: value.year,
+            semester: value.semester,
+            code: value.code,
+            class_name: value.class_name,
+            grade_points: value.grade_points,
+            score: value.score,
+            letter_grade: value
+                .letter_grade
+                .unwrap_or_else(|| LetterGrade::from_class(&value.rank, value.score)),
+            rank: value.rank,
+            professor: value.professor,
+            detail,
+            breakdown,
+        }
+    }
 }
 
 impl ClassGrade {
@@ -304,7 +359,7 @@ impl ClassGrade {
         score: ClassScore,
         rank: String,
         professor: String,
-        detail: Option<HashMap<String, f32>>,
+        breakdown: Option<GradeBreakdown>,
     ) -> ClassGrade {
         ClassGrade {
             year,
@@ -313,9 +368,11 @@ impl ClassGrade {
             class_name,
             grade_points,
             score,
+            letter_grade: LetterGrade::from_class(&rank, score),
             rank,
             professor,
-            detail,
+            detail: breakdown.as_ref().map(GradeBreakdown::raw),
+            breakdown,
         }
     }
 
@@ -354,19 +411,406 @@ impl ClassGrade {
         self.rank.as_ref()
     }
 
+    /// 등급(열거형)
+    pub fn letter_grade(&self) -> &LetterGrade {
+        &self.letter_grade
+    }
+
     /// 교수명
     pub fn professor(&self) -> &str {
         self.professor.as_ref()
     }
 
     /// 상세성적
+    ///
+    /// 비어 있는 성적은 `-1.0`으로 표현됩니다. 항목의 순서와 배점이 필요하다면 [`breakdown()`](Self::breakdown)을 이용하십시오.
     pub fn detail(&self) -> Option<&HashMap<String, f32>> {
         self.detail.as_ref()
     }
+
+    /// 항목별 상세성적
+    pub fn breakdown(&self) -> Option<&GradeBreakdown> {
+        self.breakdown.as_ref()
+    }
+}
+
+/// 상세성적의 평가 항목
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct GradeComponent {
+    /// 항목 이름
+    name: String,
+    /// 배점
+    weight: Option<f32>,
+    /// 점수
+    score: Option<f32>,
+    /// 원본 항목 이름
+    column: String,
+}
+
+impl GradeComponent {
+    /// `중간고사(30.000)` 형태의 열 이름과 점수로 평가 항목을 만듭니다. 원본 항목 이름은 그대로 보존됩니다.
+    pub(crate) fn new(column: &str, score: Option<f32>) -> Self {
+        let trimmed = column.trim();
+        let (name, weight) = trimmed
+            .strip_suffix(')')
+            .and_then(|str| str.rsplit_once('('))
+            .and_then(|(name, weight)| Some((name.trim(), weight.trim().parse::<f32>().ok()?)))
+            .map_or((trimmed, None), |(name, weight)| (name, Some(weight)));
+        Self {
+            name: name.to_string(),
+            weight,
+            score,
+            column: column.to_string(),
+        }
+    }
+
+    /// 항목 이름
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 배점, 열 이름에서 배점을 읽을 수 없다면 `None`
+    pub fn weight(&self) -> Option<f32> {
+        self.weight
+    }
+
+    /// 점수, 성적이 비어 있다면 `None`
+    pub fn score(&self) -> Option<f32> {
+        self.score
+    }
+
+    /// 유세인트에 표시되는 원본 항목 이름
+    pub fn column(&self) -> &str {
+        &self.column
+    }
+}
+
+/// 과목의 항목별 상세성적
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct GradeBreakdown {
+    /// 유세인트에 표시되는 순서대로 정렬된 평가 항목
+    components: Vec<GradeComponent>,
+}
+
+impl GradeBreakdown {
+    pub(crate) fn new(components: Vec<GradeComponent>) -> Self {
+        Self { components }
+    }
+
+    /// 유세인트에 표시되는 순서대로 정렬된 평가 항목
+    pub fn components(&self) -> &[GradeComponent] {
+        &self.components
+    }
+
+    /// 비어 있지 않은 항목 점수의 합
+    ///
+    /// 유세인트의 상세성적은 `중간고사(30.000)` 항목에 30점 만점으로 환산된 점수를 표시하므로, 별도의 가중치 계산 없이 단순 합으로 계산됩니다.
+    /// 따라서 모든 항목의 점수가 입력되었다면 [`total_weight()`](Self::total_weight) 만점 기준의 총점이 됩니다.
+    pub fn total(&self) -> f32 {
+        self.components
+            .iter()
+            .filter_map(GradeComponent::score)
+            .sum()
+    }
+
+    /// 배점을 읽을 수 있는 항목의 배점 합
+    pub fn total_weight(&self) -> f32 {
+        self.components
+            .iter()
+            .filter_map(GradeComponent::weight)
+            .sum()
+    }
+
+    /// [`raw()`](Self::raw) 형태의 맵으로 항목별 상세성적을 만듭니다. 맵에는 순서가 없으므로 항목은 원본 항목 이름 순으로 정렬됩니다.
+    pub(crate) fn from_raw(raw: HashMap<String, f32>) -> Self {
+        let mut components: Vec<GradeComponent> = raw
+            .into_iter()
+            .map(|(column, score)| GradeComponent::new(&column, (score >= 0.0).then_some(score)))
+            .collect();
+        components.sort_by(|a, b| a.column.cmp(&b.column));
+        Self { components }
+    }
+
+    /// 기존 [`ClassGrade::detail()`]과 같은 형태의 맵으로 변환합니다. 비어 있는 성적은 `-1.0`으로 표현됩니다.
+    pub fn raw(&self) -> HashMap<String, f32> {
+        self.components
+            .iter()
+            .map(|component| (component.column.clone(), component.score.unwrap_or(-1.0)))
+            .collect()
+    }
+}
+
+/// 한 학기의 과목별 성적
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct SemesterClassGrades {
+    /// 학년도
+    year: u32,
+    /// 학기
+    semester: SemesterType,
+    /// 과목별 성적
+    classes: Vec<ClassGrade>,
+}
+
+impl SemesterClassGrades {
+    pub(crate) fn new(year: u32, semester: SemesterType, classes: Vec<ClassGrade>) -> Self {
+        Self {
+            year,
+            semester,
+            classes,
+        }
+    }
+
+    /// 학년도
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// 학기
+    pub fn semester(&self) -> SemesterType {
+        self.semester
+    }
+
+    /// 과목별 성적
+    pub fn classes(&self) -> &[ClassGrade] {
+        &self.classes
+    }
+}
+
+/// 과목별 성적을 가져오지 못한 학기
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct SemesterClassGradesFailure {
+    /// 학년도
+    year: u32,
+    /// 학기
+    semester: SemesterType,
+    /// 오류 내용
+    error: String,
+}
+
+impl SemesterClassGradesFailure {
+    pub(crate) fn new(year: u32, semester: SemesterType, error: String) -> Self {
+        Self {
+            year,
+            semester,
+            error,
+        }
+    }
+
+    /// 학년도
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// 학기
+    pub fn semester(&self) -> SemesterType {
+        self.semester
+    }
+
+    /// 오류 내용
+    pub fn error(&self) -> &str {
+        &self.error
+    }
+}
+
+/// 전체 학기의 과목별 성적
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct AllClassGrades {
+    /// 학기별 과목별 성적
+    semesters: Vec<SemesterClassGrades>,
+    /// 과목별 성적을 가져오지 못한 학기
+    failures: Vec<SemesterClassGradesFailure>,
+}
+
+impl AllClassGrades {
+    pub(crate) fn new(
+        mut semesters: Vec<SemesterClassGrades>,
+        failures: Vec<SemesterClassGradesFailure>,
+    ) -> Self {
+        semesters.sort_by_key(|semester| (semester.year, semester.semester));
+        Self {
+            semesters,
+            failures,
+        }
+    }
+
+    /// 학기 순으로 정렬된 학기별 과목별 성적
+    pub fn semesters(&self) -> &[SemesterClassGrades] {
+        &self.semesters
+    }
+
+    /// 주어진 학기의 과목별 성적
+    pub fn semester(&self, year: u32, semester: SemesterType) -> Option<&SemesterClassGrades> {
+        self.semesters
+            .iter()
+            .find(|grades| grades.year == year && grades.semester == semester)
+    }
+
+    /// 전체 학기의 과목별 성적
+    pub fn classes(&self) -> impl Iterator<Item = &ClassGrade> {
+        self.semesters
+            .iter()
+            .flat_map(|semester| semester.classes.iter())
+    }
+
+    /// 과목별 성적을 가져오지 못한 학기
+    pub fn failures(&self) -> &[SemesterClassGradesFailure] {
+        &self.failures
+    }
+
+    /// 모든 학기의 과목별 성적을 가져왔는지 여부
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+/// 한 학위과정의 전체 성적과 학기별 성적
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ProgramGrades {
+    /// 학위과정
+    course_type: CourseType,
+    /// 학적부 전체 성적
+    recorded_summary: GradeSummary,
+    /// 증명 전체 성적
+    certificated_summary: GradeSummary,
+    /// 학기 순으로 정렬된 학기별 성적
+    semesters: Vec<SemesterGrade>,
+}
+
+impl ProgramGrades {
+    pub(crate) fn new(
+        course_type: CourseType,
+        recorded_summary: GradeSummary,
+        certificated_summary: GradeSummary,
+        mut semesters: Vec<SemesterGrade>,
+    ) -> Self {
+        semesters.sort_by_key(|semester| (semester.year, semester.semester));
+        Self {
+            course_type,
+            recorded_summary,
+            certificated_summary,
+            semesters,
+        }
+    }
+
+    /// 학위과정
+    pub fn course_type(&self) -> CourseType {
+        self.course_type
+    }
+
+    /// 학적부 전체 성적
+    pub fn recorded_summary(&self) -> &GradeSummary {
+        &self.recorded_summary
+    }
+
+    /// 증명 전체 성적
+    pub fn certificated_summary(&self) -> &GradeSummary {
+        &self.certificated_summary
+    }
+
+    /// 학기 순으로 정렬된 학기별 성적
+    pub fn semesters(&self) -> &[SemesterGrade] {
+        &self.semesters
+    }
+}
+
+/// 성적을 가져오지 못한 학위과정
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ProgramGradesFailure {
+    /// 학위과정
+    course_type: CourseType,
+    /// 오류 내용
+    error: String,
+}
+
+impl ProgramGradesFailure {
+    pub(crate) fn new(course_type: CourseType, error: String) -> Self {
+        Self { course_type, error }
+    }
+
+    /// 학위과정
+    pub fn course_type(&self) -> CourseType {
+        self.course_type
+    }
+
+    /// 오류 내용
+    pub fn error(&self) -> &str {
+        &self.error
+    }
+}
+
+/// 학생이 이수한 모든 학위과정의 성적
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct AcademicHistory {
+    /// 처음 학기 순으로 정렬된 학위과정별 성적
+    programs: Vec<ProgramGrades>,
+    /// 성적을 가져오지 못한 학위과정
+    #[serde(default)]
+    failures: Vec<ProgramGradesFailure>,
+}
+
+impl AcademicHistory {
+    pub(crate) fn new(
+        mut programs: Vec<ProgramGrades>,
+        failures: Vec<ProgramGradesFailure>,
+    ) -> Self {
+        programs.sort_by_key(|program| {
+            program
+                .semesters
+                .first()
+                .map(|semester| (semester.year, semester.semester))
+        });
+        Self { programs, failures }
+    }
+
+    /// 성적을 가져오지 못한 학위과정
+    pub fn failures(&self) -> &[ProgramGradesFailure] {
+        &self.failures
+    }
+
+    /// 모든 학위과정의 성적을 가져왔는지 여부
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// 처음 학기 순으로 정렬된 학위과정별 성적
+    pub fn programs(&self) -> &[ProgramGrades] {
+        &self.programs
+    }
+
+    /// 주어진 학위과정의 성적
+    pub fn program(&self, course_type: CourseType) -> Option<&ProgramGrades> {
+        self.programs
+            .iter()
+            .find(|program| program.course_type == course_type)
+    }
+
+    /// 모든 학위과정의 학기별 성적을 학기 순으로 정렬하여 학위과정과 함께 반환합니다.
+    pub fn semesters(&self) -> Vec<(CourseType, &SemesterGrade)> {
+        let mut semesters: Vec<(CourseType, &SemesterGrade)> = self
+            .programs
+            .iter()
+            .flat_map(|program| {
+                program
+                    .semesters
+                    .iter()
+                    .map(|semester| (program.course_type, semester))
+            })
+            .collect();
+        semesters.sort_by_key(|(_, semester)| (semester.year, semester.semester));
+        semesters
+    }
 }
 
 /// 학위과정
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[allow(unused)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
 pub enum CourseType {
@@ -383,7 +827,7 @@ pub enum CourseType {
 }
 
 /// 과목 점수
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[allow(unused)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
 pub enum ClassScore {
@@ -397,6 +841,153 @@ pub enum ClassScore {
     Empty,
 }
 
+/// 과목 등급
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum LetterGrade {
+    /// A+ (4.5)
+    APlus,
+    /// A0 (4.3)
+    AZero,
+    /// A- (4.0)
+    AMinus,
+    /// B+ (3.5)
+    BPlus,
+    /// B0 (3.3)
+    BZero,
+    /// B- (3.0)
+    BMinus,
+    /// C+ (2.5)
+    CPlus,
+    /// C0 (2.3)
+    CZero,
+    /// C- (2.0)
+    CMinus,
+    /// D+ (1.5)
+    DPlus,
+    /// D0 (1.3)
+    DZero,
+    /// D- (1.0)
+    DMinus,
+    /// F (0.0)
+    F,
+    /// P/F 과목의 Pass
+    Pass,
+    /// P/F 과목의 Fail
+    Fail,
+    /// 수강철회(W)
+    Withdrawn,
+    /// 미완(I)
+    Incomplete,
+    /// 성적 없음
+    Empty,
+    /// 알 수 없는 등급
+    Other(String),
+}
+
+impl LetterGrade {
+    /// 등급 문자열을 변환합니다. `F`는 일반 과목의 F 등급으로 취급합니다.
+    pub fn from_rank(rank: &str) -> LetterGrade {
+        match rank.trim() {
+            "A+" => LetterGrade::APlus,
+            "A0" | "A" => LetterGrade::AZero,
+            "A-" => LetterGrade::AMinus,
+            "B+" => LetterGrade::BPlus,
+            "B0" | "B" => LetterGrade::BZero,
+            "B-" => LetterGrade::BMinus,
+            "C+" => LetterGrade::CPlus,
+            "C0" | "C" => LetterGrade::CZero,
+            "C-" => LetterGrade::CMinus,
+            "D+" => LetterGrade::DPlus,
+            "D0" | "D" => LetterGrade::DZero,
+            "D-" => LetterGrade::DMinus,
+            "F" => LetterGrade::F,
+            "P" => LetterGrade::Pass,
+            "NP" => LetterGrade::Fail,
+            "W" => LetterGrade::Withdrawn,
+            "I" => LetterGrade::Incomplete,
+            "" => LetterGrade::Empty,
+            other => LetterGrade::Other(other.to_string()),
+        }
+    }
+
+    /// 등급 문자열과 과목 점수로 등급을 판단합니다. P/F 과목의 `F`는 [`LetterGrade::Fail`]로 취급합니다.
+    pub(crate) fn from_class(rank: &str, score: ClassScore) -> LetterGrade {
+        match (score, LetterGrade::from_rank(rank)) {
+            (ClassScore::Pass, _) => LetterGrade::Pass,
+            (ClassScore::Failed, _) => LetterGrade::Fail,
+            (_, grade) => grade,
+        }
+    }
+
+    /// 숭실대학교 4.5 만점 기준 평점을 반환합니다. 평점 계산에 포함되지 않는 등급은 `None`을 반환합니다.
+    pub fn grade_point(&self) -> Option<f32> {
+        let point = match self {
+            LetterGrade::APlus => 4.5,
+            LetterGrade::AZero => 4.3,
+            LetterGrade::AMinus => 4.0,
+            LetterGrade::BPlus => 3.5,
+            LetterGrade::BZero => 3.3,
+            LetterGrade::BMinus => 3.0,
+            LetterGrade::CPlus => 2.5,
+            LetterGrade::CZero => 2.3,
+            LetterGrade::CMinus => 2.0,
+            LetterGrade::DPlus => 1.5,
+            LetterGrade::DZero => 1.3,
+            LetterGrade::DMinus => 1.0,
+            LetterGrade::F => 0.0,
+            _ => return None,
+        };
+        Some(point)
+    }
+
+    /// P/F 과목의 등급인지 여부를 반환합니다.
+    pub fn is_pass_fail(&self) -> bool {
+        matches!(self, LetterGrade::Pass | LetterGrade::Fail)
+    }
+
+    /// 수강철회 여부를 반환합니다.
+    pub fn is_withdrawal(&self) -> bool {
+        matches!(self, LetterGrade::Withdrawn)
+    }
+
+    /// 미완 여부를 반환합니다.
+    pub fn is_incomplete(&self) -> bool {
+        matches!(self, LetterGrade::Incomplete)
+    }
+
+    /// 낙제(F, P/F 과목의 Fail) 여부를 반환합니다.
+    pub fn is_failing(&self) -> bool {
+        matches!(self, LetterGrade::F | LetterGrade::Fail)
+    }
+}
+
+impl std::fmt::Display for LetterGrade {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            LetterGrade::APlus => "A+",
+            LetterGrade::AZero => "A0",
+            LetterGrade::AMinus => "A-",
+            LetterGrade::BPlus => "B+",
+            LetterGrade::BZero => "B0",
+            LetterGrade::BMinus => "B-",
+            LetterGrade::CPlus => "C+",
+            LetterGrade::CZero => "C0",
+            LetterGrade::CMinus => "C-",
+            LetterGrade::DPlus => "D+",
+            LetterGrade::DZero => "D0",
+            LetterGrade::DMinus => "D-",
+            LetterGrade::F | LetterGrade::Fail => "F",
+            LetterGrade::Pass => "P",
+            LetterGrade::Withdrawn => "W",
+            LetterGrade::Incomplete => "I",
+            LetterGrade::Empty => "",
+            LetterGrade::Other(str) => str,
+        };
+        write!(f, "{str}")
+    }
+}
+
 impl FromStr for ClassScore {
     type Err = ParseIntError;
 
@@ -409,3 +1000,85 @@ impl FromStr for ClassScore {
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{ClassGrade, ClassScore, GradeBreakdown, GradeComponent, LetterGrade};
+    use crate::model::SemesterType;
+
+    #[test]
+    fn letter_grade() {
+        assert_eq!(LetterGrade::from_rank("A+").grade_point(), Some(4.5));
+        assert_eq!(LetterGrade::from_rank("B0").grade_point(), Some(3.3));
+        assert_eq!(LetterGrade::from_rank("F").grade_point(), Some(0.0));
+        assert_eq!(
+            LetterGrade::from_class("F", ClassScore::Failed),
+            LetterGrade::Fail
+        );
+        assert!(LetterGrade::from_rank("P").is_pass_fail());
+        assert!(LetterGrade::from_rank("W").is_withdrawal());
+        assert_eq!(LetterGrade::from_rank("W").grade_point(), None);
+        assert_eq!(LetterGrade::from_rank("C-").to_string(), "C-");
+    }
+
+    #[test]
+    fn grade_breakdown() {
+        let breakdown = GradeBreakdown::new(vec![
+            GradeComponent::new("출석(20.000)", Some(20.0)),
+            GradeComponent::new("중간고사(30.000)", Some(27.5)),
+            GradeComponent::new("기말고사(50.000)", None),
+            GradeComponent::new("태도", Some(1.0)),
+        ]);
+        let components = breakdown.components();
+        assert_eq!(components[1].name(), "중간고사");
+        assert_eq!(components[1].weight(), Some(30.0));
+        assert_eq!(components[3].name(), "태도");
+        assert_eq!(components[3].weight(), None);
+        assert_eq!(breakdown.total(), 48.5);
+        assert_eq!(breakdown.total_weight(), 100.0);
+        let raw = breakdown.raw();
+        assert_eq!(raw["기말고사(50.000)"], -1.0);
+        assert_eq!(raw["중간고사(30.000)"], 27.5);
+        let untrimmed = GradeBreakdown::new(vec![GradeComponent::new(" 과제(10.000) ", Some(9.0))]);
+        assert_eq!(untrimmed.components()[0].name(), "과제");
+        assert_eq!(untrimmed.raw()[" 과제(10.000) "], 9.0);
+    }
+
+    #[test]
+    fn deserialize_legacy_detail() {
+        let grade = ClassGrade::new(
+            2024,
+            SemesterType::One,
+            "21500123".to_string(),
+            "자료구조".to_string(),
+            3.0,
+            ClassScore::Score(95),
+            "A+".to_string(),
+            "홍길동".to_string(),
+            Some(GradeBreakdown::new(vec![GradeComponent::new(
+                "중간고사(30.000)",
+                Some(27.5),
+            )])),
+        );
+        let mut json = serde_json::to_value(&grade).unwrap();
+        assert_eq!(json["detail"]["중간고사(30.000)"], 27.5);
+        let reloaded: ClassGrade = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(reloaded.breakdown(), grade.breakdown());
+        assert_eq!(reloaded.detail(), grade.detail());
+
+        let object = json.as_object_mut().unwrap();
+        object.remove("breakdown");
+        object.remove("letter_grade");
+        object.remove("detail");
+        object.insert(
+            "detail".to_string(),
+            serde_json::json!({ "중간고사(30.000)": 27.5, "기말고사(50.000)": -1.0 }),
+        );
+        let legacy: ClassGrade = serde_json::from_value(json).unwrap();
+        let components = legacy.breakdown().unwrap().components();
+        assert_eq!(components[0].name(), "기말고사");
+        assert_eq!(components[0].score(), None);
+        assert_eq!(legacy.detail().unwrap()["중간고사(30.000)"], 27.5);
+        assert_eq!(legacy.letter_grade(), &LetterGrade::APlus);
+    }
+}
@@ -0,0 +1,356 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    application::course_grades::{
+        model::{ClassGrade, ClassScore, GradeSummary},
+        simulator::gpa_credits,
+    },
+    model::SemesterType,
+};
+
+/// 차이가 설명된 것으로 판단하는 오차 범위
+const TOLERANCE: f32 = 0.05;
+
+/// 학적부 성적과 증명 성적에 차이가 생기는 이유
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum ReconciliationReason {
+    /// F 등급을 받아 증명 성적에서 제외됨
+    Failed,
+    /// 이후 학기에 재수강하여 증명 성적에서 제외됨
+    Retaken {
+        /// 재수강한 학년도
+        year: u32,
+        /// 재수강한 학기
+        semester: SemesterType,
+    },
+}
+
+/// 학적부 성적과 증명 성적의 차이를 만드는 과목
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ReconciliationItem {
+    /// 과목 성적
+    class: ClassGrade,
+    /// 차이가 생기는 이유
+    reason: ReconciliationReason,
+    /// 신청학점 차이
+    attempted_credits: f32,
+    /// 취득학점 차이
+    earned_credits: f32,
+    /// 평점 계산에 포함되는 학점 차이
+    graded_credits: f32,
+    /// 평점계 차이
+    grade_points_sum: f32,
+}
+
+impl ReconciliationItem {
+    fn new(class: &ClassGrade, reason: ReconciliationReason) -> Self {
+        let credits = class.grade_points();
+        let letter_grade = class.letter_grade();
+        let grade_point = matches!(class.score(), ClassScore::Score(_))
+            .then(|| letter_grade.grade_point())
+            .flatten();
+        let earned = !letter_grade.is_failing()
+            && !letter_grade.is_withdrawal()
+            && !letter_grade.is_incomplete();
+        Self {
+            class: class.clone(),
+            reason,
+            attempted_credits: credits,
+            earned_credits: if earned { credits } else { 0.0 },
+            graded_credits: if grade_point.is_some() { credits } else { 0.0 },
+            grade_points_sum: grade_point.map_or(0.0, |grade_point| credits * grade_point),
+        }
+    }
+
+    /// 과목 성적
+    pub fn class(&self) -> &ClassGrade {
+        &self.class
+    }
+
+    /// 차이가 생기는 이유
+    pub fn reason(&self) -> &ReconciliationReason {
+        &self.reason
+    }
+
+    /// 이 과목으로 인한 신청학점 차이(학적부 - 증명)
+    pub fn attempted_credits(&self) -> f32 {
+        self.attempted_credits
+    }
+
+    /// 이 과목으로 인한 취득학점 차이(학적부 - 증명)
+    pub fn earned_credits(&self) -> f32 {
+        self.earned_credits
+    }
+
+    /// 이 과목으로 인한 평점 계산 학점 차이(학적부 - 증명)
+    pub fn graded_credits(&self) -> f32 {
+        self.graded_credits
+    }
+
+    /// 이 과목으로 인한 평점계 차이(학적부 - 증명)
+    pub fn grade_points_sum(&self) -> f32 {
+        self.grade_points_sum
+    }
+}
+
+/// 학적부 성적과 증명 성적의 한 항목 비교
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct SummaryDifference {
+    /// 학적부 값
+    recorded: f32,
+    /// 증명 값
+    certificated: f32,
+    /// 과목별 성적으로 설명되는 차이
+    explained: f32,
+}
+
+impl SummaryDifference {
+    /// 학적부 값
+    pub fn recorded(&self) -> f32 {
+        self.recorded
+    }
+
+    /// 증명 값
+    pub fn certificated(&self) -> f32 {
+        self.certificated
+    }
+
+    /// 전체 차이(학적부 - 증명)
+    pub fn difference(&self) -> f32 {
+        self.recorded - self.certificated
+    }
+
+    /// 과목별 성적으로 설명되는 차이
+    pub fn explained(&self) -> f32 {
+        self.explained
+    }
+
+    /// 과목별 성적으로 설명되지 않는 차이
+    pub fn unexplained(&self) -> f32 {
+        self.difference() - self.explained
+    }
+}
+
+/// 학적부 성적과 증명 성적의 차이를 과목별로 설명한 결과
+///
+/// 증명 성적은 F 등급을 받은 과목과 재수강으로 대체된 이전 성적을 제외하고 계산됩니다.
+/// 과목별 성적으로 설명되지 않는 차이가 있다면 [`SummaryDifference::unexplained()`]로 확인할 수 있습니다.
+/// ### 예시
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// # use std::sync::Arc;
+/// # use rusaint::USaintSession;
+/// # use rusaint::application::USaintClientBuilder;
+/// # use rusaint::application::course_grades::{CourseGradesApplication, model::CourseType};
+/// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+/// let mut app = USaintClientBuilder::new().session(session).build_into::<CourseGradesApplication>().await.unwrap();
+/// let reconciliation = app.summary_reconciliation(CourseType::Bachelor).await.unwrap();
+/// for item in reconciliation.items() {
+///     println!("{}: {:?}", item.class().class_name(), item.reason());
+/// }
+/// # })
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct SummaryReconciliation {
+    /// 신청학점
+    attempted_credits: SummaryDifference,
+    /// 취득학점
+    earned_credits: SummaryDifference,
+    /// 평점계
+    grade_points_sum: SummaryDifference,
+    /// 평점평균
+    grade_points_average: SummaryDifference,
+    /// 차이를 만드는 과목 목록
+    items: Vec<ReconciliationItem>,
+}
+
+impl SummaryReconciliation {
+    /// 학적부 성적, 증명 성적과 전체 학기의 과목별 성적으로 두 성적의 차이를 설명합니다.
+    pub fn new<'a>(
+        recorded: &GradeSummary,
+        certificated: &GradeSummary,
+        classes: impl IntoIterator<Item = &'a ClassGrade>,
+    ) -> Self {
+        let items = items(classes);
+        let (attempted, earned, graded, sum) = items.iter().fold(
+            (0.0, 0.0, 0.0, 0.0),
+            |(attempted, earned, graded, sum), item| {
+                (
+                    attempted + item.attempted_credits,
+                    earned + item.earned_credits,
+                    graded + item.graded_credits,
+                    sum + item.grade_points_sum,
+                )
+            },
+        );
+        let recorded_graded =
+            gpa_credits(recorded.grade_points_sum(), recorded.grade_points_average());
+        let expected_graded = recorded_graded - graded;
+        let expected_average = if expected_graded > 0.0 {
+            (recorded.grade_points_sum() - sum) / expected_graded
+        } else {
+            0.0
+        };
+        Self {
+            attempted_credits: SummaryDifference {
+                recorded: recorded.attempted_credits(),
+                certificated: certificated.attempted_credits(),
+                explained: attempted,
+            },
+            earned_credits: SummaryDifference {
+                recorded: recorded.earned_credits(),
+                certificated: certificated.earned_credits(),
+                explained: earned,
+            },
+            grade_points_sum: SummaryDifference {
+                recorded: recorded.grade_points_sum(),
+                certificated: certificated.grade_points_sum(),
+                explained: sum,
+            },
+            grade_points_average: SummaryDifference {
+                recorded: recorded.grade_points_average(),
+                certificated: certificated.grade_points_average(),
+                explained: recorded.grade_points_average() - expected_average,
+            },
+            items,
+        }
+    }
+
+    /// 신청학점 비교
+    pub fn attempted_credits(&self) -> SummaryDifference {
+        self.attempted_credits
+    }
+
+    /// 취득학점 비교
+    pub fn earned_credits(&self) -> SummaryDifference {
+        self.earned_credits
+    }
+
+    /// 평점계 비교
+    pub fn grade_points_sum(&self) -> SummaryDifference {
+        self.grade_points_sum
+    }
+
+    /// 평점평균 비교
+    pub fn grade_points_average(&self) -> SummaryDifference {
+        self.grade_points_average
+    }
+
+    /// 차이를 만드는 과목 목록
+    pub fn items(&self) -> &[ReconciliationItem] {
+        &self.items
+    }
+
+    /// 모든 차이가 과목별 성적으로 설명되는지 여부
+    pub fn is_fully_explained(&self) -> bool {
+        [
+            self.attempted_credits,
+            self.earned_credits,
+            self.grade_points_sum,
+            self.grade_points_average,
+        ]
+        .iter()
+        .all(|difference| difference.unexplained().abs() < TOLERANCE)
+    }
+}
+
+fn items<'a>(classes: impl IntoIterator<Item = &'a ClassGrade>) -> Vec<ReconciliationItem> {
+    let mut classes: Vec<&ClassGrade> = classes.into_iter().collect();
+    classes.sort_by_key(|class| (class.year(), class.semester()));
+    // 학기 순으로 정렬된 목록에서 과목코드별로 가장 마지막 수강의 위치를 기록합니다.
+    let latest: HashMap<&str, usize> = classes
+        .iter()
+        .enumerate()
+        .map(|(index, class)| (class.code(), index))
+        .collect();
+    classes
+        .iter()
+        .enumerate()
+        .filter_map(|(index, class)| {
+            let latest_index = latest[class.code()];
+            if latest_index != index {
+                let latest = classes[latest_index];
+                Some(ReconciliationItem::new(
+                    class,
+                    ReconciliationReason::Retaken {
+                        year: latest.year(),
+                        semester: latest.semester(),
+                    },
+                ))
+            } else if class.letter_grade().is_failing() {
+                Some(ReconciliationItem::new(class, ReconciliationReason::Failed))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ReconciliationReason, SummaryReconciliation};
+    use crate::{
+        application::course_grades::model::{ClassGrade, ClassScore, GradeSummary, LetterGrade},
+        model::SemesterType,
+    };
+
+    fn class(year: u32, code: &str, score: ClassScore, rank: &str) -> ClassGrade {
+        ClassGrade::new(
+            year,
+            SemesterType::One,
+            code.to_string(),
+            code.to_string(),
+            3.0,
+            score,
+            rank.to_string(),
+            "홍길동".to_string(),
+            None,
+        )
+    }
+
+    #[test]
+    fn reconcile_summaries() {
+        let classes = vec![
+            class(2024, "A", ClassScore::Score(97), "A+"),
+            class(2023, "A", ClassScore::Score(50), "F"),
+            class(2023, "B", ClassScore::Score(40), "F"),
+            class(2023, "C", ClassScore::Score(84), "B0"),
+        ];
+        // 학적부는 모든 수강 기록을, 증명은 최종 재수강 성적과 F가 아닌 성적만 반영합니다.
+        let points = |grade: LetterGrade| 3.0 * grade.grade_point().unwrap();
+        let sum = points(LetterGrade::APlus) + points(LetterGrade::BZero);
+        let recorded = GradeSummary::new(12.0, 6.0, sum, sum / 12.0, 67.75, 0.0);
+        let certificated = GradeSummary::new(6.0, 6.0, sum, sum / 6.0, 90.5, 0.0);
+        let reconciliation = SummaryReconciliation::new(&recorded, &certificated, &classes);
+        let items = reconciliation.items();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].class().code(), "A");
+        assert_eq!(
+            items[0].reason(),
+            &ReconciliationReason::Retaken {
+                year: 2024,
+                semester: SemesterType::One
+            }
+        );
+        assert_eq!(items[1].reason(), &ReconciliationReason::Failed);
+        assert_eq!(reconciliation.attempted_credits().explained(), 6.0);
+        assert_eq!(reconciliation.earned_credits().explained(), 0.0);
+        assert_eq!(reconciliation.grade_points_sum().explained(), 0.0);
+        let average = reconciliation.grade_points_average();
+        assert!((average.difference() - (sum / 12.0 - sum / 6.0)).abs() < 1e-5);
+        assert!(average.unexplained().abs() < 1e-5);
+        assert!(reconciliation.is_fully_explained());
+
+        // 증명 성적에 과목별 성적으로 설명되지 않는 차이가 있다면 감지합니다.
+        let mismatched = GradeSummary::new(6.0, 6.0, sum - 0.9, (sum - 0.9) / 6.0, 90.5, 0.0);
+        let reconciliation = SummaryReconciliation::new(&recorded, &mismatched, &classes);
+        assert!((reconciliation.grade_points_sum().unexplained() - 0.9).abs() < 1e-5);
+        assert!(!reconciliation.is_fully_explained());
+    }
+}
@@ -0,0 +1,264 @@
+use serde::{Deserialize, Serialize};
+
+use crate::application::course_grades::{
+    gpa::Gpa,
+    model::{ClassGrade, ClassScore, GradeSummary, LetterGrade, SemesterGrade},
+};
+
+/// 전체 성적의 종류
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum GradeSummaryKind {
+    /// 학적부 성적, F 등급도 평점 계산에 포함됩니다.
+    Recorded,
+    /// 증명 성적, F 등급은 평점 계산에서 제외됩니다.
+    Certificated,
+}
+
+impl GradeSummaryKind {
+    /// 해당 평점이 이 종류의 평점 계산에 포함되는지 여부를 반환합니다.
+    fn counts(self, grade_point: f32) -> bool {
+        match self {
+            GradeSummaryKind::Recorded => true,
+            GradeSummaryKind::Certificated => grade_point > 0.0,
+        }
+    }
+}
+
+/// 가상 성적을 반영한 예상 평점
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct GpaProjection {
+    /// 가상 과목만으로 계산한 이번 학기 예상 평점
+    semester: Gpa,
+    /// 기존 성적과 가상 과목을 합산한 예상 누적 평점
+    cumulative: Gpa,
+}
+
+impl GpaProjection {
+    /// 가상 과목만으로 계산한 이번 학기 예상 평점
+    pub fn semester(&self) -> Gpa {
+        self.semester
+    }
+
+    /// 기존 성적과 가상 과목을 합산한 예상 누적 평점
+    pub fn cumulative(&self) -> Gpa {
+        self.cumulative
+    }
+}
+
+#[derive(Clone, Debug)]
+struct HypotheticalCourse {
+    credits: f32,
+    grade_point: f32,
+    replaces: Option<f32>,
+}
+
+/// 가상 성적을 반영하여 학기 및 누적 평점을 예측하는 시뮬레이터
+///
+/// 기존 누적 평점의 평점 계산 학점은 평점계를 평점평균으로 나누어 계산합니다.
+/// ### 예시
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// # use std::sync::Arc;
+/// # use rusaint::USaintSession;
+/// # use rusaint::application::USaintClientBuilder;
+/// # use rusaint::application::course_grades::{CourseGradesApplication, model::{CourseType, LetterGrade}, simulator::{GpaSimulator, GradeSummaryKind}};
+/// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+/// let mut app = USaintClientBuilder::new().session(session).build_into::<CourseGradesApplication>().await.unwrap();
+/// let summary = app.recorded_summary(CourseType::Bachelor).await.unwrap();
+/// let projection = GpaSimulator::new(&summary, GradeSummaryKind::Recorded)
+///     .course(3.0, LetterGrade::APlus)
+///     .course(3.0, LetterGrade::BZero)
+///     .simulate();
+/// println!("{}", projection.cumulative().average());
+/// let required = GpaSimulator::new(&summary, GradeSummaryKind::Recorded).required_average(18.0, 4.0);
+/// println!("{:?}", required);
+/// # })
+/// ```
+#[derive(Clone, Debug)]
+pub struct GpaSimulator {
+    kind: GradeSummaryKind,
+    base: Gpa,
+    courses: Vec<HypotheticalCourse>,
+}
+
+impl GpaSimulator {
+    /// 전체 성적(학적부 또는 증명)을 기준으로 새로운 시뮬레이터를 만듭니다.
+    pub fn new(summary: &GradeSummary, kind: GradeSummaryKind) -> Self {
+        Self::with_base(
+            kind,
+            summary.grade_points_sum(),
+            summary.grade_points_average(),
+        )
+    }
+
+    /// 학기별 성적을 합산한 값을 기준으로 새로운 시뮬레이터를 만듭니다.
+    pub fn from_semesters<'a>(
+        semesters: impl IntoIterator<Item = &'a SemesterGrade>,
+        kind: GradeSummaryKind,
+    ) -> Self {
+        let base = semesters
+            .into_iter()
+            .fold(Gpa::default(), |base, semester| {
+                let credits =
+                    gpa_credits(semester.grade_points_sum(), semester.grade_points_average());
+                Gpa::new(
+                    base.credits() + credits,
+                    base.grade_points_sum() + semester.grade_points_sum(),
+                )
+            });
+        Self {
+            kind,
+            base,
+            courses: Vec::new(),
+        }
+    }
+
+    fn with_base(kind: GradeSummaryKind, grade_points_sum: f32, average: f32) -> Self {
+        Self {
+            kind,
+            base: Gpa::new(gpa_credits(grade_points_sum, average), grade_points_sum),
+            courses: Vec::new(),
+        }
+    }
+
+    /// 주어진 학점과 등급의 가상 과목을 추가합니다. 평점이 없는 등급(P, W 등)은 무시됩니다.
+    pub fn course(mut self, credits: f32, grade: LetterGrade) -> Self {
+        if let Some(grade_point) = grade.grade_point() {
+            self.courses.push(HypotheticalCourse {
+                credits,
+                grade_point,
+                replaces: None,
+            });
+        }
+        self
+    }
+
+    /// 기존에 수강한 과목을 주어진 등급으로 재수강하는 가상 과목을 추가합니다.
+    ///
+    /// 재수강 시 기존 성적은 평점 계산에서 제외되고 재수강 성적으로 대체됩니다.
+    pub fn retake(mut self, previous: &ClassGrade, grade: LetterGrade) -> Self {
+        let Some(grade_point) = grade.grade_point() else {
+            return self;
+        };
+        let replaces = matches!(previous.score(), ClassScore::Score(_))
+            .then(|| previous.letter_grade().grade_point())
+            .flatten();
+        self.courses.push(HypotheticalCourse {
+            credits: previous.grade_points(),
+            grade_point,
+            replaces,
+        });
+        self
+    }
+
+    /// 가상 과목을 반영한 이번 학기 및 누적 예상 평점을 계산합니다.
+    pub fn simulate(&self) -> GpaProjection {
+        let mut semester = Gpa::default();
+        let mut cumulative = self.base;
+        for course in &self.courses {
+            if let Some(previous) = course.replaces {
+                if self.kind.counts(previous) {
+                    cumulative.add(-course.credits, previous);
+                }
+            }
+            if self.kind.counts(course.grade_point) {
+                semester.add(course.credits, course.grade_point);
+                cumulative.add(course.credits, course.grade_point);
+            }
+        }
+        GpaProjection {
+            semester,
+            cumulative,
+        }
+    }
+
+    /// 가상 과목을 반영한 상태에서, 이번 학기에 추가로 `credits` 학점을 수강하여 누적 평점 `target`을 달성하기 위해 필요한 평점평균을 계산합니다.
+    ///
+    /// 필요한 평점평균이 4.5를 넘어 달성할 수 없다면 `None`을, 이미 목표를 달성할 수 있다면 `0.0`을 반환합니다.
+    pub fn required_average(&self, credits: f32, target: f32) -> Option<f32> {
+        if credits <= 0.0 {
+            return None;
+        }
+        let cumulative = self.simulate().cumulative;
+        let required =
+            (target * (cumulative.credits() + credits) - cumulative.grade_points_sum()) / credits;
+        if required > MAX_GRADE_POINT {
+            None
+        } else {
+            Some(required.max(0.0))
+        }
+    }
+}
+
+/// 4.5 만점 평점의 최댓값
+const MAX_GRADE_POINT: f32 = 4.5;
+
+/// 평점계와 평점평균으로 평점 계산에 포함된 학점을 계산합니다.
+pub(crate) fn gpa_credits(grade_points_sum: f32, average: f32) -> f32 {
+    if average <= 0.0 {
+        0.0
+    } else {
+        (grade_points_sum / average * 2.0).round() / 2.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{GpaSimulator, GradeSummaryKind};
+    use crate::{
+        application::course_grades::model::{ClassGrade, ClassScore, GradeSummary, LetterGrade},
+        model::SemesterType,
+    };
+
+    fn summary() -> GradeSummary {
+        // 30학점, 평점평균 3.5
+        GradeSummary::new(33.0, 30.0, 105.0, 3.5, 85.0, 0.0)
+    }
+
+    #[test]
+    fn simulate_courses() {
+        let projection = GpaSimulator::new(&summary(), GradeSummaryKind::Recorded)
+            .course(3.0, LetterGrade::APlus)
+            .course(3.0, LetterGrade::BPlus)
+            .course(1.0, LetterGrade::Pass)
+            .simulate();
+        assert_eq!(projection.semester().credits(), 6.0);
+        assert_eq!(projection.semester().average(), 4.0);
+        assert_eq!(projection.cumulative().credits(), 36.0);
+        assert!((projection.cumulative().average() - 129.0 / 36.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn simulate_retake() {
+        let failed = ClassGrade::new(
+            2023,
+            SemesterType::One,
+            "A".to_string(),
+            "A".to_string(),
+            3.0,
+            ClassScore::Score(50),
+            "F".to_string(),
+            "홍길동".to_string(),
+            None,
+        );
+        let recorded = GpaSimulator::new(&summary(), GradeSummaryKind::Recorded)
+            .retake(&failed, LetterGrade::AZero)
+            .simulate();
+        assert_eq!(recorded.cumulative().credits(), 30.0);
+        assert!((recorded.cumulative().grade_points_sum() - 117.9).abs() < 1e-3);
+        let certificated = GpaSimulator::new(&summary(), GradeSummaryKind::Certificated)
+            .retake(&failed, LetterGrade::AZero)
+            .simulate();
+        assert_eq!(certificated.cumulative().credits(), 33.0);
+    }
+
+    #[test]
+    fn required_average() {
+        let simulator = GpaSimulator::new(&summary(), GradeSummaryKind::Recorded);
+        assert_eq!(simulator.required_average(15.0, 3.8), Some(4.4));
+        assert_eq!(simulator.required_average(15.0, 4.2), None);
+        assert_eq!(simulator.required_average(15.0, 1.0), Some(0.0));
+    }
+}
@@ -0,0 +1,424 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    application::course_grades::{model::SemesterGrade, simulator::GpaProjection},
+    model::SemesterType,
+};
+
+/// 평점 추세를 계산할 때 사용하는 최근 정규학기(신청학점이 있는 학기) 수
+const TREND_WINDOW: usize = 4;
+
+/// 평점 추세를 상승 또는 하락으로 판단하는 학기당 평점 변화량
+const TREND_THRESHOLD: f32 = 0.1;
+
+/// 학사경고 및 제적 기준
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct StandingPolicy {
+    /// 학사경고 기준 평점평균, 정규학기 평점평균이 이 값 미만이면 학사경고 대상입니다.
+    #[cfg_attr(feature = "uniffi", uniffi(default = 1.75))]
+    probation_threshold: f32,
+    /// 제적 대상이 되는 연속 학사경고 횟수
+    #[cfg_attr(feature = "uniffi", uniffi(default = 3))]
+    dismissal_probations: u32,
+}
+
+impl Default for StandingPolicy {
+    fn default() -> Self {
+        Self {
+            probation_threshold: 1.75,
+            dismissal_probations: 3,
+        }
+    }
+}
+
+impl StandingPolicy {
+    /// 주어진 기준으로 새로운 [`StandingPolicy`]를 만듭니다.
+    pub fn new(probation_threshold: f32, dismissal_probations: u32) -> Self {
+        Self {
+            probation_threshold,
+            dismissal_probations,
+        }
+    }
+
+    /// 학사경고 기준 평점평균
+    pub fn probation_threshold(&self) -> f32 {
+        self.probation_threshold
+    }
+
+    /// 제적 대상이 되는 연속 학사경고 횟수
+    pub fn dismissal_probations(&self) -> u32 {
+        self.dismissal_probations
+    }
+}
+
+/// 학기별 학업 상태
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct SemesterStanding {
+    /// 학년도
+    year: u32,
+    /// 학기
+    semester: SemesterType,
+    /// 평점평균
+    grade_points_average: f32,
+    /// 학사경고 여부
+    academic_probation: bool,
+    /// 상담여부
+    consult: bool,
+    /// 유급 여부
+    flunked: bool,
+    /// 학기별석차 백분위(상위 %)
+    semester_percentile: Option<f32>,
+    /// 전체석차 백분위(상위 %)
+    general_percentile: Option<f32>,
+}
+
+impl SemesterStanding {
+    fn from_semester(semester: &SemesterGrade) -> Self {
+        Self {
+            year: semester.year(),
+            semester: semester.semester(),
+            grade_points_average: semester.grade_points_average(),
+            academic_probation: semester.academic_probation(),
+            consult: semester.consult(),
+            flunked: semester.flunked(),
+            semester_percentile: percentile(semester.semester_rank()),
+            general_percentile: percentile(semester.general_rank()),
+        }
+    }
+
+    /// 학년도
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// 학기
+    pub fn semester(&self) -> SemesterType {
+        self.semester
+    }
+
+    /// 평점평균
+    pub fn grade_points_average(&self) -> f32 {
+        self.grade_points_average
+    }
+
+    /// 학사경고 여부
+    pub fn academic_probation(&self) -> bool {
+        self.academic_probation
+    }
+
+    /// 상담여부
+    pub fn consult(&self) -> bool {
+        self.consult
+    }
+
+    /// 유급 여부
+    pub fn flunked(&self) -> bool {
+        self.flunked
+    }
+
+    /// 학기별석차 백분위(상위 %), 석차 정보가 없으면 `None`을 반환합니다.
+    pub fn semester_percentile(&self) -> Option<f32> {
+        self.semester_percentile
+    }
+
+    /// 전체석차 백분위(상위 %), 석차 정보가 없으면 `None`을 반환합니다.
+    pub fn general_percentile(&self) -> Option<f32> {
+        self.general_percentile
+    }
+}
+
+/// 최근 정규학기의 평점 추세
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum GpaTrend {
+    /// 상승
+    Rising,
+    /// 유지
+    Steady,
+    /// 하락
+    Falling,
+    /// 정규학기 성적이 부족하여 판단할 수 없음
+    Unknown,
+}
+
+/// 연속 학사경고에 따른 제적 위험도
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum ProbationRisk {
+    /// 직전 학기에 학사경고를 받지 않음
+    Safe,
+    /// 직전 학기에 학사경고를 받음
+    Warned,
+    /// 이번 학기에 학사경고를 받으면 제적 대상이 됨
+    Critical,
+    /// 연속 학사경고로 제적 대상임
+    Dismissal,
+}
+
+impl ProbationRisk {
+    fn from_consecutive(consecutive_probations: u32, policy: &StandingPolicy) -> Self {
+        match consecutive_probations {
+            0 => ProbationRisk::Safe,
+            count if count >= policy.dismissal_probations => ProbationRisk::Dismissal,
+            count if count + 1 >= policy.dismissal_probations => ProbationRisk::Critical,
+            _ => ProbationRisk::Warned,
+        }
+    }
+}
+
+/// 학기별 성적으로 분석한 학업 상태
+///
+/// 학사경고는 정규학기(1학기, 2학기)에만 부여되므로 계절학기 성적은 분석에서 제외됩니다.
+/// ### 예시
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// # use std::sync::Arc;
+/// # use rusaint::USaintSession;
+/// # use rusaint::application::USaintClientBuilder;
+/// # use rusaint::application::course_grades::{CourseGradesApplication, model::{CourseType, LetterGrade}, simulator::{GpaSimulator, GradeSummaryKind}, standing::StandingPolicy};
+/// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+/// let mut app = USaintClientBuilder::new().session(session).build_into::<CourseGradesApplication>().await.unwrap();
+/// let standing = app.academic_standing(CourseType::Bachelor, &StandingPolicy::default()).await.unwrap();
+/// println!("{:?} {:?}", standing.risk(), standing.trend());
+/// let summary = app.recorded_summary(CourseType::Bachelor).await.unwrap();
+/// let projection = GpaSimulator::new(&summary, GradeSummaryKind::Recorded)
+///     .course(3.0, LetterGrade::DZero)
+///     .course(3.0, LetterGrade::F)
+///     .simulate();
+/// if standing.projected_probation(&projection) {
+///     println!("예상 위험도: {:?}", standing.projected_risk(&projection));
+/// }
+/// # })
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct AcademicStanding {
+    /// 학기 순으로 정렬된 정규학기별 학업 상태
+    semesters: Vec<SemesterStanding>,
+    /// 가장 최근 학기까지 연속으로 받은 학사경고 횟수
+    consecutive_probations: u32,
+    /// 전체 학사경고 횟수
+    total_probations: u32,
+    /// 최근 정규학기의 평점 추세
+    trend: GpaTrend,
+    /// 최근 정규학기 평점의 학기당 변화량
+    trend_slope: Option<f32>,
+    /// 연속 학사경고에 따른 제적 위험도
+    risk: ProbationRisk,
+    /// 분석에 사용한 학사경고 및 제적 기준
+    policy: StandingPolicy,
+}
+
+impl AcademicStanding {
+    /// 학기별 성적을 주어진 학사경고 및 제적 기준으로 분석합니다.
+    pub fn analyze<'a>(
+        semesters: impl IntoIterator<Item = &'a SemesterGrade>,
+        policy: &StandingPolicy,
+    ) -> Self {
+        let mut regular: Vec<&SemesterGrade> = semesters
+            .into_iter()
+            .filter(|semester| matches!(semester.semester(), SemesterType::One | SemesterType::Two))
+            .collect();
+        regular.sort_by_key(|semester| (semester.year(), semester.semester()));
+        let semesters: Vec<SemesterStanding> = regular
+            .iter()
+            .copied()
+            .map(SemesterStanding::from_semester)
+            .collect();
+        let consecutive_probations = semesters
+            .iter()
+            .rev()
+            .take_while(|semester| semester.academic_probation)
+            .count() as u32;
+        let total_probations = semesters
+            .iter()
+            .filter(|semester| semester.academic_probation)
+            .count() as u32;
+        let recent: Vec<f32> = regular
+            .iter()
+            .filter(|semester| semester.attempted_credits() > 0.0)
+            .map(|semester| semester.grade_points_average())
+            .collect();
+        let trend_slope = slope(&recent[recent.len().saturating_sub(TREND_WINDOW)..]);
+        let trend = match trend_slope {
+            Some(slope) if slope >= TREND_THRESHOLD => GpaTrend::Rising,
+            Some(slope) if slope <= -TREND_THRESHOLD => GpaTrend::Falling,
+            Some(_) => GpaTrend::Steady,
+            None => GpaTrend::Unknown,
+        };
+        Self {
+            semesters,
+            consecutive_probations,
+            total_probations,
+            trend,
+            trend_slope,
+            risk: ProbationRisk::from_consecutive(consecutive_probations, policy),
+            policy: *policy,
+        }
+    }
+
+    /// 학기 순으로 정렬된 정규학기별 학업 상태
+    pub fn semesters(&self) -> &[SemesterStanding] {
+        &self.semesters
+    }
+
+    /// 가장 최근 학기까지 연속으로 받은 학사경고 횟수
+    pub fn consecutive_probations(&self) -> u32 {
+        self.consecutive_probations
+    }
+
+    /// 전체 학사경고 횟수
+    pub fn total_probations(&self) -> u32 {
+        self.total_probations
+    }
+
+    /// 최근 정규학기의 평점 추세
+    pub fn trend(&self) -> GpaTrend {
+        self.trend
+    }
+
+    /// 최근 정규학기 평점의 학기당 변화량, 정규학기 성적이 두 학기 미만이면 `None`을 반환합니다.
+    pub fn trend_slope(&self) -> Option<f32> {
+        self.trend_slope
+    }
+
+    /// 연속 학사경고에 따른 제적 위험도
+    pub fn risk(&self) -> ProbationRisk {
+        self.risk
+    }
+
+    /// 분석에 사용한 학사경고 및 제적 기준
+    pub fn policy(&self) -> &StandingPolicy {
+        &self.policy
+    }
+
+    /// 예상 학기 평점이 학사경고 기준에 해당하는지 여부를 반환합니다.
+    pub fn projected_probation(&self, projection: &GpaProjection) -> bool {
+        let semester = projection.semester();
+        semester.credits() > 0.0 && semester.average() < self.policy.probation_threshold
+    }
+
+    /// 예상 학기 평점이 반영되었을 때의 제적 위험도를 반환합니다.
+    pub fn projected_risk(&self, projection: &GpaProjection) -> ProbationRisk {
+        if self.projected_probation(projection) {
+            ProbationRisk::from_consecutive(self.consecutive_probations + 1, &self.policy)
+        } else {
+            ProbationRisk::Safe
+        }
+    }
+}
+
+/// 석차(등수, 인원)를 상위 백분위로 변환합니다.
+fn percentile((rank, total): (u32, u32)) -> Option<f32> {
+    (rank > 0 && total > 0 && rank <= total).then(|| rank as f32 / total as f32 * 100.0)
+}
+
+/// 최소제곱법으로 계산한 학기당 평점 변화량
+fn slope(values: &[f32]) -> Option<f32> {
+    if values.len() < 2 {
+        return None;
+    }
+    let n = values.len() as f32;
+    let mean_x = (n - 1.0) / 2.0;
+    let mean_y = values.iter().sum::<f32>() / n;
+    let (numerator, denominator) =
+        values
+            .iter()
+            .enumerate()
+            .fold((0.0, 0.0), |(numerator, denominator), (x, y)| {
+                let dx = x as f32 - mean_x;
+                (numerator + dx * (y - mean_y), denominator + dx * dx)
+            });
+    Some(numerator / denominator)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AcademicStanding, GpaTrend, ProbationRisk, StandingPolicy};
+    use crate::application::{
+        course_grades::{
+            model::{GradeSummary, LetterGrade, SemesterGrade},
+            simulator::{GpaSimulator, GradeSummaryKind},
+        },
+        utils::from_fields,
+    };
+
+    fn semester(year: u32, semester: &str, average: &str, probation: bool) -> SemesterGrade {
+        semester_with_credits(year, semester, "18", average, probation)
+    }
+
+    fn semester_with_credits(
+        year: u32,
+        semester: &str,
+        credits: &str,
+        average: &str,
+        probation: bool,
+    ) -> SemesterGrade {
+        let year = year.to_string();
+        from_fields(&[
+            ("학년도", year.as_str()),
+            ("학기", semester),
+            ("신청학점", credits),
+            ("취득학점", credits),
+            ("P/F학점", "0"),
+            ("평점평균", average),
+            ("평점계", "0"),
+            ("산술평균", "0"),
+            ("학기별석차", "10/200"),
+            ("전체석차", "0/0"),
+            ("학사경고", if probation { "Y" } else { "" }),
+            ("상담여부", ""),
+            ("유급", ""),
+        ])
+    }
+
+    #[test]
+    fn analyze_standing() {
+        let semesters = vec![
+            semester(2023, "2 학기", "1.50", true),
+            semester(2023, "1 학기", "3.00", false),
+            semester(2023, "여름학기", "4.50", false),
+            semester(2024, "1 학기", "1.20", true),
+        ];
+        let standing = AcademicStanding::analyze(&semesters, &StandingPolicy::default());
+        assert_eq!(standing.semesters().len(), 3);
+        assert_eq!(standing.semesters()[0].year(), 2023);
+        assert_eq!(standing.semesters()[0].semester_percentile(), Some(5.0));
+        assert_eq!(standing.semesters()[0].general_percentile(), None);
+        assert_eq!(standing.consecutive_probations(), 2);
+        assert_eq!(standing.total_probations(), 2);
+        assert_eq!(standing.trend(), GpaTrend::Falling);
+        assert_eq!(standing.risk(), ProbationRisk::Critical);
+
+        let summary = GradeSummary::new(54.0, 54.0, 103.5, 1.92, 70.0, 0.0);
+        let projection = GpaSimulator::new(&summary, GradeSummaryKind::Recorded)
+            .course(3.0, LetterGrade::DPlus)
+            .course(3.0, LetterGrade::F)
+            .simulate();
+        assert!(standing.projected_probation(&projection));
+        assert_eq!(
+            standing.projected_risk(&projection),
+            ProbationRisk::Dismissal
+        );
+
+        let lenient = AcademicStanding::analyze(&semesters, &StandingPolicy::new(0.5, 4));
+        assert_eq!(lenient.risk(), ProbationRisk::Warned);
+        assert!(!lenient.projected_probation(&projection));
+        assert_eq!(lenient.projected_risk(&projection), ProbationRisk::Safe);
+    }
+
+    #[test]
+    fn trend_includes_zero_average_semester() {
+        let semesters = vec![
+            semester(2023, "1 학기", "3.00", false),
+            semester(2023, "2 학기", "0.00", true),
+            semester_with_credits(2024, "1 학기", "0", "0.00", false),
+        ];
+        let standing = AcademicStanding::analyze(&semesters, &StandingPolicy::default());
+        assert_eq!(standing.semesters().len(), 3);
+        assert_eq!(standing.trend_slope(), Some(-3.0));
+        assert_eq!(standing.trend(), GpaTrend::Falling);
+    }
+}
@@ -0,0 +1,514 @@
+use std::{fmt::Write as _, sync::Arc};
+
+use html_escape::encode_text;
+use serde::Serialize;
+
+use crate::{
+    RusaintError, USaintSession,
+    application::{
+        USaintClientBuilder,
+        course_grades::{
+            CourseGradesApplication,
+            model::{
+                AllClassGrades, ClassGrade, ClassScore, CourseType, GradeSummary, SemesterGrade,
+            },
+        },
+        student_information::{StudentInformationApplication, model::StudentInformation},
+    },
+};
+
+/// 성적표에 표시되는 학생 정보
+///
+/// 주민등록번호, 주소 등 성적표에 필요하지 않은 개인정보는 포함하지 않습니다.
+#[derive(Clone, Debug, Serialize)]
+pub struct TranscriptStudent {
+    student_number: u32,
+    name: String,
+    collage: String,
+    department: String,
+    major: Option<String>,
+    grade: u32,
+    term: u32,
+}
+
+impl TranscriptStudent {
+    fn from_information(student: &StudentInformation) -> Self {
+        Self {
+            student_number: student.student_number(),
+            name: student.name().to_string(),
+            collage: student.collage().to_string(),
+            department: student.department().to_string(),
+            major: student.major().map(str::to_string),
+            grade: student.grade(),
+            term: student.term(),
+        }
+    }
+
+    /// 학번
+    pub fn student_number(&self) -> u32 {
+        self.student_number
+    }
+
+    /// 이름
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 소속 대학
+    pub fn collage(&self) -> &str {
+        &self.collage
+    }
+
+    /// 소속 학과(부)
+    pub fn department(&self) -> &str {
+        &self.department
+    }
+
+    /// 전공
+    pub fn major(&self) -> Option<&str> {
+        self.major.as_deref()
+    }
+
+    /// 학년
+    pub fn grade(&self) -> u32 {
+        self.grade
+    }
+
+    /// 학기
+    pub fn term(&self) -> u32 {
+        self.term
+    }
+}
+
+/// 학생 정보, 전체 성적, 학기별 성적, 과목별 성적을 하나로 합친 비공식 성적표
+///
+/// CSV, JSON, 인쇄 가능한 HTML 형식으로 내보낼 수 있습니다.
+/// ### 예시
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// # use std::sync::Arc;
+/// # use rusaint::USaintSession;
+/// # use rusaint::application::course_grades::{model::CourseType, transcript::Transcript};
+/// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+/// let transcript = Transcript::fetch(session, CourseType::Bachelor).await.unwrap();
+/// std::fs::write("transcript.html", transcript.to_html()).unwrap();
+/// std::fs::write("transcript.csv", transcript.to_csv()).unwrap();
+/// # })
+/// ```
+#[derive(Debug, Serialize)]
+pub struct Transcript {
+    student: TranscriptStudent,
+    recorded_summary: GradeSummary,
+    certificated_summary: GradeSummary,
+    semesters: Vec<SemesterGrade>,
+    classes: AllClassGrades,
+}
+
+impl Transcript {
+    /// 이미 가져온 정보로 성적표를 만듭니다.
+    pub fn new(
+        student: &StudentInformation,
+        recorded_summary: GradeSummary,
+        certificated_summary: GradeSummary,
+        semesters: Vec<SemesterGrade>,
+        classes: AllClassGrades,
+    ) -> Self {
+        Self::with_student(
+            TranscriptStudent::from_information(student),
+            recorded_summary,
+            certificated_summary,
+            semesters,
+            classes,
+        )
+    }
+
+    fn with_student(
+        student: TranscriptStudent,
+        recorded_summary: GradeSummary,
+        certificated_summary: GradeSummary,
+        mut semesters: Vec<SemesterGrade>,
+        classes: AllClassGrades,
+    ) -> Self {
+        semesters.sort_by_key(|semester| (semester.year(), semester.semester()));
+        Self {
+            student,
+            recorded_summary,
+            certificated_summary,
+            semesters,
+            classes,
+        }
+    }
+
+    /// 주어진 세션으로 학생 정보와 성적 정보를 모두 가져와 성적표를 만듭니다.
+    pub async fn fetch(
+        session: Arc<USaintSession>,
+        course_type: CourseType,
+    ) -> Result<Self, RusaintError> {
+        let student_app = USaintClientBuilder::new()
+            .session(session.clone())
+            .build_into::<StudentInformationApplication>()
+            .await?;
+        let student = student_app.general()?;
+        let mut app = USaintClientBuilder::new()
+            .session(session)
+            .build_into::<CourseGradesApplication>()
+            .await?;
+        let recorded_summary = app.recorded_summary(course_type).await?;
+        let certificated_summary = app.certificated_summary(course_type).await?;
+        let semesters = app.semesters(course_type).await?;
+        let classes = app.all_classes(course_type, false).await?;
+        Ok(Self::new(
+            &student,
+            recorded_summary,
+            certificated_summary,
+            semesters,
+            classes,
+        ))
+    }
+
+    /// 학생 정보
+    pub fn student(&self) -> &TranscriptStudent {
+        &self.student
+    }
+
+    /// 학적부 전체 성적
+    pub fn recorded_summary(&self) -> &GradeSummary {
+        &self.recorded_summary
+    }
+
+    /// 증명 전체 성적
+    pub fn certificated_summary(&self) -> &GradeSummary {
+        &self.certificated_summary
+    }
+
+    /// 학기 순으로 정렬된 학기별 성적
+    pub fn semesters(&self) -> &[SemesterGrade] {
+        &self.semesters
+    }
+
+    /// 과목별 성적
+    pub fn classes(&self) -> &AllClassGrades {
+        &self.classes
+    }
+
+    /// 성적표를 CSV 형식으로 변환합니다.
+    ///
+    /// 전체 성적, 학기별 성적, 과목별 성적 순으로 구역을 나누며, 각 구역은 `[구역 이름]` 행과 머리글 행으로 시작하고 빈 행으로 구분됩니다.
+    pub fn to_csv(&self) -> String {
+        let mut csv =
+            String::from("[전체 성적]\n구분,신청학점,취득학점,평점계,평점평균,산술평균,P/F학점\n");
+        for (name, summary) in [
+            ("학적부", &self.recorded_summary),
+            ("증명", &self.certificated_summary),
+        ] {
+            push_csv_row(
+                &mut csv,
+                &[
+                    name.to_string(),
+                    summary.attempted_credits().to_string(),
+                    summary.earned_credits().to_string(),
+                    summary.grade_points_sum().to_string(),
+                    summary.grade_points_average().to_string(),
+                    summary.arithmetic_mean().to_string(),
+                    summary.pf_earned_credits().to_string(),
+                ],
+            );
+        }
+        csv.push_str("\n[학기별 성적]\n학년도,학기,신청학점,취득학점,P/F학점,평점계,평점평균,산술평균,학기별석차,전체석차\n");
+        for semester in &self.semesters {
+            let (semester_rank, semester_total) = semester.semester_rank();
+            let (general_rank, general_total) = semester.general_rank();
+            push_csv_row(
+                &mut csv,
+                &[
+                    semester.year().to_string(),
+                    semester.semester().to_string(),
+                    semester.attempted_credits().to_string(),
+                    semester.earned_credits().to_string(),
+                    semester.pf_earned_credits().to_string(),
+                    semester.grade_points_sum().to_string(),
+                    semester.grade_points_average().to_string(),
+                    semester.arithmetic_mean().to_string(),
+                    format!("{semester_rank}/{semester_total}"),
+                    format!("{general_rank}/{general_total}"),
+                ],
+            );
+        }
+        csv.push_str("\n[과목별 성적]\n학년도,학기,과목코드,과목명,과목학점,성적,등급,교수명\n");
+        for class in self.classes.classes() {
+            push_csv_row(
+                &mut csv,
+                &[
+                    class.year().to_string(),
+                    class.semester().to_string(),
+                    class.code().to_string(),
+                    class.class_name().to_string(),
+                    class.grade_points().to_string(),
+                    score_text(class),
+                    class.rank().to_string(),
+                    class.professor().to_string(),
+                ],
+            );
+        }
+        csv
+    }
+
+    /// 성적표를 JSON 형식으로 변환합니다. 객체의 키는 항상 사전순으로 정렬되어 같은 성적표는 항상 같은 문자열로 변환됩니다.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        // `serde_json::Value`의 객체는 키 순서대로 정렬되므로 `HashMap`이 포함되어도 결과가 일정합니다.
+        let value = serde_json::to_value(self)?;
+        serde_json::to_string_pretty(&value)
+    }
+
+    /// 외부 리소스 없이 인쇄할 수 있는 HTML 문서로 변환합니다.
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        let student = &self.student;
+        let _ = write!(
+            html,
+            "<!DOCTYPE html>\n<html lang=\"ko\">\n<head>\n<meta charset=\"utf-8\">\n<title>성적표 - {}</title>\n<style>{}</style>\n</head>\n<body>\n<h1>성적표</h1>\n",
+            encode_text(student.name()),
+            HTML_STYLE
+        );
+        let _ = writeln!(
+            html,
+            "<table class=\"student\"><tr><th>학번</th><td>{}</td><th>이름</th><td>{}</td></tr><tr><th>소속</th><td>{} {}</td><th>전공</th><td>{}</td></tr><tr><th>학년</th><td>{}학년 {}학기</td><th></th><td></td></tr></table>",
+            student.student_number(),
+            encode_text(student.name()),
+            encode_text(student.collage()),
+            encode_text(student.department()),
+            encode_text(student.major().unwrap_or_default()),
+            student.grade(),
+            student.term()
+        );
+        html.push_str("<h2>전체 성적</h2>\n<table><tr><th>구분</th><th>신청학점</th><th>취득학점</th><th>평점계</th><th>평점평균</th><th>산술평균</th><th>P/F학점</th></tr>\n");
+        for (name, summary) in [
+            ("학적부", &self.recorded_summary),
+            ("증명", &self.certificated_summary),
+        ] {
+            let _ = writeln!(
+                html,
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                name,
+                summary.attempted_credits(),
+                summary.earned_credits(),
+                summary.grade_points_sum(),
+                summary.grade_points_average(),
+                summary.arithmetic_mean(),
+                summary.pf_earned_credits()
+            );
+        }
+        html.push_str("</table>\n");
+        for grades in self.classes.semesters() {
+            let _ = writeln!(
+                html,
+                "<section class=\"semester\">\n<h2>{}학년도 {}</h2>",
+                grades.year(),
+                grades.semester()
+            );
+            if let Some(semester) = self.semesters.iter().find(|semester| {
+                semester.year() == grades.year() && semester.semester() == grades.semester()
+            }) {
+                let _ = writeln!(
+                    html,
+                    "<p>신청학점 {} / 취득학점 {} / 평점평균 {} / 학기별석차 {}/{}</p>",
+                    semester.attempted_credits(),
+                    semester.earned_credits(),
+                    semester.grade_points_average(),
+                    semester.semester_rank().0,
+                    semester.semester_rank().1
+                );
+            }
+            html.push_str("<table><tr><th>과목코드</th><th>과목명</th><th>학점</th><th>성적</th><th>등급</th><th>교수명</th></tr>\n");
+            for class in grades.classes() {
+                let _ = writeln!(
+                    html,
+                    "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>",
+                    encode_text(class.code()),
+                    encode_text(class.class_name()),
+                    class.grade_points(),
+                    encode_text(&score_text(class)),
+                    encode_text(class.rank()),
+                    encode_text(class.professor())
+                );
+            }
+            html.push_str("</table>\n</section>\n");
+        }
+        if !self.classes.failures().is_empty() {
+            html.push_str("<h2>가져오지 못한 학기</h2>\n<ul>\n");
+            for failure in self.classes.failures() {
+                let _ = writeln!(
+                    html,
+                    "<li>{}학년도 {}: {}</li>",
+                    failure.year(),
+                    failure.semester(),
+                    encode_text(failure.error())
+                );
+            }
+            html.push_str("</ul>\n");
+        }
+        html.push_str("<p class=\"notice\">이 성적표는 유세인트에서 가져온 정보로 만든 비공식 성적표입니다.</p>\n</body>\n</html>\n");
+        html
+    }
+}
+
+const HTML_STYLE: &str = "body{font-family:sans-serif;margin:2em;color:#111}h1{text-align:center}table{border-collapse:collapse;width:100%;margin-bottom:1em}th,td{border:1px solid #999;padding:4px 8px;text-align:center}th{background:#eee}.semester{page-break-inside:avoid}.notice{font-size:small;color:#555}@media print{body{margin:0}}";
+
+fn score_text(class: &ClassGrade) -> String {
+    match class.score() {
+        ClassScore::Pass => "P".to_string(),
+        ClassScore::Failed => "F".to_string(),
+        ClassScore::Score(score) => score.to_string(),
+        ClassScore::Empty => String::new(),
+    }
+}
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn push_csv_row(csv: &mut String, row: &[String]) {
+    let row: Vec<String> = row.iter().map(|field| escape_csv(field)).collect();
+    csv.push_str(&row.join(","));
+    csv.push('\n');
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Transcript, TranscriptStudent, escape_csv};
+    use crate::{
+        application::{
+            course_grades::model::{
+                AllClassGrades, ClassGrade, ClassScore, GradeSummary, SemesterClassGrades,
+                SemesterClassGradesFailure, SemesterGrade,
+            },
+            utils::from_fields,
+        },
+        model::SemesterType,
+    };
+
+    fn transcript() -> Transcript {
+        let student = TranscriptStudent {
+            student_number: 20212345,
+            name: "<홍길동>".to_string(),
+            collage: "IT대학".to_string(),
+            department: "컴퓨터학부".to_string(),
+            major: None,
+            grade: 3,
+            term: 2,
+        };
+        let semester: SemesterGrade = from_fields(&[
+            ("학년도", "2024"),
+            ("학기", "1 학기"),
+            ("신청학점", "6"),
+            ("취득학점", "6"),
+            ("P/F학점", "0"),
+            ("평점평균", "4.25"),
+            ("평점계", "25.5"),
+            ("산술평균", "93"),
+            ("학기별석차", "3/50"),
+            ("전체석차", "10/200"),
+            ("상담여부", ""),
+            ("유급", ""),
+        ]);
+        let class = |code: &str, name: &str, score: ClassScore, rank: &str| {
+            ClassGrade::new(
+                2024,
+                SemesterType::One,
+                code.to_string(),
+                name.to_string(),
+                3.0,
+                score,
+                rank.to_string(),
+                "홍길동".to_string(),
+                None,
+            )
+        };
+        let classes = AllClassGrades::new(
+            vec![SemesterClassGrades::new(
+                2024,
+                SemesterType::One,
+                vec![
+                    class("21500123", "C, C++ 프로그래밍", ClassScore::Score(95), "A+"),
+                    class("21500456", "자료구조", ClassScore::Score(91), "A0"),
+                ],
+            )],
+            vec![SemesterClassGradesFailure::new(
+                2023,
+                SemesterType::Two,
+                "<timeout>".to_string(),
+            )],
+        );
+        Transcript::with_student(
+            student,
+            GradeSummary::new(6.0, 6.0, 25.5, 4.25, 93.0, 0.0),
+            GradeSummary::new(6.0, 6.0, 25.5, 4.25, 93.0, 0.0),
+            vec![semester],
+            classes,
+        )
+    }
+
+    #[test]
+    fn escape_fields() {
+        assert_eq!(escape_csv("자료구조"), "자료구조");
+        assert_eq!(escape_csv("C, C++"), "\"C, C++\"");
+        assert_eq!(escape_csv("\"인용\""), "\"\"\"인용\"\"\"");
+    }
+
+    #[test]
+    fn export_csv() {
+        let csv = transcript().to_csv();
+        let sections: Vec<&str> = csv.split("\n\n").collect();
+        assert_eq!(sections.len(), 3);
+        assert_eq!(
+            sections[0].lines().collect::<Vec<_>>(),
+            vec![
+                "[전체 성적]",
+                "구분,신청학점,취득학점,평점계,평점평균,산술평균,P/F학점",
+                "학적부,6,6,25.5,4.25,93,0",
+                "증명,6,6,25.5,4.25,93,0",
+            ]
+        );
+        assert_eq!(
+            sections[1].lines().nth(2),
+            Some("2024,1학기,6,6,0,25.5,4.25,93,3/50,10/200")
+        );
+        let classes: Vec<&str> = sections[2].lines().collect();
+        assert_eq!(classes.len(), 4);
+        assert_eq!(
+            classes[2],
+            "2024,1학기,21500123,\"C, C++ 프로그래밍\",3,95,A+,홍길동"
+        );
+    }
+
+    #[test]
+    fn export_html() {
+        let html = transcript().to_html();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>성적표 - &lt;홍길동&gt;</title>"));
+        assert!(html.contains("<h2>2024학년도 1학기</h2>"));
+        assert!(html.contains("학기별석차 3/50"));
+        assert!(html.contains("<td>C, C++ 프로그래밍</td>"));
+        assert!(html.contains("<li>2023학년도 2학기: &lt;timeout&gt;</li>"));
+        assert!(!html.contains("<홍길동>"));
+    }
+
+    #[test]
+    fn export_json() {
+        let transcript = transcript();
+        let json = transcript.to_json().unwrap();
+        assert_eq!(json, transcript.to_json().unwrap());
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["student"]["student_number"], 20212345);
+        assert_eq!(value["semesters"][0]["semester_rank"][0], 3);
+        assert_eq!(
+            value["classes"]["semesters"][0]["classes"][1]["class_name"],
+            "자료구조"
+        );
+        assert_eq!(value["classes"]["failures"][0]["error"], "<timeout>");
+    }
+}
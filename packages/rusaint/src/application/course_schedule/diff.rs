@@ -0,0 +1,313 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::course_schedule::{
+    model::Lecture,
+    watcher::{rooms, times},
+};
+
+/// 비교 대상이 되는 강의 정보 항목
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LectureField {
+    /// 과목명
+    Name,
+    /// 교수명
+    Professor,
+    /// 강의 요일 또는 시간, 변경 전후 값은 강의시간(강의실)입니다.
+    Schedule,
+    /// 강의 시간은 그대로인 강의실, 변경 전후 값은 강의시간(강의실)입니다.
+    Room,
+    /// 수강인원
+    Personeel,
+    /// 시간/학점(설계)
+    TimePoints,
+    /// 이수구분(주전공)
+    Category,
+    /// 이수구분(다전공)
+    SubCategory,
+    /// 수강대상
+    Target,
+}
+
+impl LectureField {
+    const ALL: [LectureField; 9] = [
+        LectureField::Name,
+        LectureField::Professor,
+        LectureField::Schedule,
+        LectureField::Room,
+        LectureField::Personeel,
+        LectureField::TimePoints,
+        LectureField::Category,
+        LectureField::SubCategory,
+        LectureField::Target,
+    ];
+
+    fn value(self, lecture: &Lecture) -> Option<&str> {
+        match self {
+            LectureField::Name => Some(lecture.name()),
+            LectureField::Professor => Some(lecture.professor()),
+            LectureField::Schedule | LectureField::Room => Some(lecture.schedule_room()),
+            LectureField::Personeel => Some(lecture.personeel()),
+            LectureField::TimePoints => Some(lecture.time_points()),
+            LectureField::Category => Some(lecture.category()),
+            LectureField::SubCategory => lecture.sub_category(),
+            LectureField::Target => Some(lecture.target()),
+        }
+    }
+
+    fn changed(self, before: &Lecture, after: &Lecture) -> bool {
+        match self {
+            LectureField::Schedule => schedule_changed(before, after),
+            LectureField::Room => !schedule_changed(before, after) && rooms(before) != rooms(after),
+            _ => self.value(before) != self.value(after),
+        }
+    }
+}
+
+/// 강의실을 제외한 요일별 강의 시간이 변경되었는지 여부, 강의 시간을 해석할 수 없다면 강의시간(강의실) 문자열을 비교합니다.
+fn schedule_changed(before: &Lecture, after: &Lecture) -> bool {
+    let (before_times, after_times) = (times(before), times(after));
+    before_times != after_times
+        || (before_times.is_empty() && before.schedule_room() != after.schedule_room())
+}
+
+/// 강의 정보 항목 하나의 변경 내용
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LectureFieldChange {
+    field: LectureField,
+    before: Option<String>,
+    after: Option<String>,
+}
+
+impl LectureFieldChange {
+    /// 변경된 항목을 반환합니다.
+    pub fn field(&self) -> LectureField {
+        self.field
+    }
+
+    /// 변경 전 값을 반환합니다.
+    pub fn before(&self) -> Option<&str> {
+        self.before.as_deref()
+    }
+
+    /// 변경 후 값을 반환합니다.
+    pub fn after(&self) -> Option<&str> {
+        self.after.as_deref()
+    }
+}
+
+/// 두 목록에 모두 존재하지만 정보가 변경된 강의
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ChangedLecture {
+    code: String,
+    division: Option<String>,
+    name: String,
+    changes: Vec<LectureFieldChange>,
+}
+
+impl ChangedLecture {
+    /// 과목번호를 반환합니다.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// 분반을 반환합니다.
+    pub fn division(&self) -> Option<&str> {
+        self.division.as_deref()
+    }
+
+    /// 변경 후 과목명을 반환합니다.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 변경된 항목 목록을 반환합니다.
+    pub fn changes(&self) -> &[LectureFieldChange] {
+        &self.changes
+    }
+}
+
+/// 두 강의 목록을 과목번호와 분반 기준으로 비교한 결과
+///
+/// ### 예시
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// # use std::sync::Arc;
+/// # use rusaint::USaintSession;
+/// # use rusaint::application::USaintClientBuilder;
+/// # use rusaint::application::course_schedule::{CourseScheduleApplication, diff::LectureDiff, model::LectureCategory};
+/// # use rusaint::model::SemesterType;
+/// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+/// let mut app = USaintClientBuilder::new().session(session).build_into::<CourseScheduleApplication>().await.unwrap();
+/// let category = LectureCategory::major("IT대학", "컴퓨터학부", None);
+/// let current: Vec<_> = app.find_lectures(2025, SemesterType::One, &category).await.unwrap().collect();
+/// let next: Vec<_> = app.find_lectures(2025, SemesterType::Two, &category).await.unwrap().collect();
+/// let diff = LectureDiff::between(&current, &next);
+/// println!("{}", serde_json::to_string_pretty(&diff).unwrap());
+/// # })
+/// ```
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct LectureDiff {
+    added: Vec<Lecture>,
+    removed: Vec<Lecture>,
+    changed: Vec<ChangedLecture>,
+}
+
+type LectureKey<'a> = (&'a str, Option<&'a str>);
+
+impl LectureDiff {
+    /// 이전 강의 목록과 이후 강의 목록을 비교합니다. 결과는 과목번호, 분반 순으로 정렬됩니다.
+    ///
+    /// 한 목록에 같은 과목번호와 분반을 가진 강의가 여러 개 있다면 마지막 강의를 기준으로 비교합니다.
+    pub fn between(before: &[Lecture], after: &[Lecture]) -> Self {
+        let before = keyed(before);
+        let after = keyed(after);
+        let added = after
+            .iter()
+            .filter(|(key, _)| !before.contains_key(*key))
+            .map(|(_, lecture)| (*lecture).clone())
+            .collect();
+        let removed = before
+            .iter()
+            .filter(|(key, _)| !after.contains_key(*key))
+            .map(|(_, lecture)| (*lecture).clone())
+            .collect();
+        let changed = before
+            .iter()
+            .filter_map(|(key, before)| {
+                let after = after.get(key)?;
+                let changes = field_changes(before, after);
+                (!changes.is_empty()).then(|| ChangedLecture {
+                    code: after.code().to_string(),
+                    division: after.division().map(str::to_string),
+                    name: after.name().to_string(),
+                    changes,
+                })
+            })
+            .collect();
+        Self {
+            added,
+            removed,
+            changed,
+        }
+    }
+
+    /// 이후 목록에만 존재하는 강의 목록을 반환합니다.
+    pub fn added(&self) -> &[Lecture] {
+        &self.added
+    }
+
+    /// 이전 목록에만 존재하는 강의 목록을 반환합니다.
+    pub fn removed(&self) -> &[Lecture] {
+        &self.removed
+    }
+
+    /// 정보가 변경된 강의 목록을 반환합니다.
+    pub fn changed(&self) -> &[ChangedLecture] {
+        &self.changed
+    }
+
+    /// 두 목록 사이에 차이가 없는지 여부를 반환합니다.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+fn keyed(lectures: &[Lecture]) -> BTreeMap<LectureKey<'_>, &Lecture> {
+    lectures
+        .iter()
+        .map(|lecture| ((lecture.code(), lecture.division()), lecture))
+        .collect()
+}
+
+fn field_changes(before: &Lecture, after: &Lecture) -> Vec<LectureFieldChange> {
+    LectureField::ALL
+        .into_iter()
+        .filter(|field| field.changed(before, after))
+        .map(|field| LectureFieldChange {
+            field,
+            before: field.value(before).map(str::to_string),
+            after: field.value(after).map(str::to_string),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{LectureDiff, LectureField};
+    use crate::application::course_schedule::model::{self, Lecture};
+
+    fn lecture(code: &str, division: &str, professor: &str, schedule_room: &str) -> Lecture {
+        model::test::lecture(&[
+            ("과목번호", code),
+            ("과목명", code),
+            ("분반", division),
+            ("교수명", professor),
+            ("강의시간(강의실)", schedule_room),
+        ])
+    }
+
+    #[test]
+    fn diff_lectures() {
+        let before = vec![
+            lecture("A", "01", "홍길동", "월 09:00-10:15"),
+            lecture("A", "02", "홍길동", "화 09:00-10:15"),
+            lecture("B", "01", "김철수", "수 09:00-10:15"),
+        ];
+        let after = vec![
+            lecture("A", "01", "홍길동", "월 09:00-10:15"),
+            lecture("B", "01", "이영희", "목 09:00-10:15"),
+            lecture("C", "01", "김철수", "금 09:00-10:15"),
+        ];
+        let diff = LectureDiff::between(&before, &after);
+        assert_eq!(diff.added()[0].code(), "C");
+        assert_eq!(diff.removed()[0].division(), Some("02"));
+        assert_eq!(diff.changed().len(), 1);
+        let fields: Vec<LectureField> = diff.changed()[0]
+            .changes()
+            .iter()
+            .map(|change| change.field())
+            .collect();
+        assert_eq!(
+            fields,
+            vec![LectureField::Professor, LectureField::Schedule]
+        );
+        assert!(LectureDiff::between(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn diff_schedule_and_room() {
+        let before = vec![lecture(
+            "A",
+            "01",
+            "홍길동",
+            "월 09:00-10:15 (정보과학관 21203)",
+        )];
+        let fields = |schedule_room: &str| -> Vec<LectureField> {
+            let after = vec![lecture("A", "01", "홍길동", schedule_room)];
+            LectureDiff::between(&before, &after)
+                .changed()
+                .iter()
+                .flat_map(|lecture| lecture.changes().iter().map(|change| change.field()))
+                .collect()
+        };
+        assert_eq!(
+            fields("월 09:00-10:15 (정보과학관 21204)"),
+            vec![LectureField::Room]
+        );
+        assert_eq!(
+            fields("화 09:00-10:15 (정보과학관 21204)"),
+            vec![LectureField::Schedule]
+        );
+        assert!(fields("월 09:00-10:15   (정보과학관 21203)").is_empty());
+    }
+
+    #[test]
+    fn reload_serialized_lectures() {
+        let lectures = vec![lecture("A", "01", "홍길동", "월 09:00-10:15")];
+        let json = serde_json::to_string(&lectures).unwrap();
+        let reloaded: Vec<Lecture> = serde_json::from_str(&json).unwrap();
+        assert!(LectureDiff::between(&lectures, &reloaded).is_empty());
+    }
+}
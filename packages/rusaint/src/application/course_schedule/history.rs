@@ -0,0 +1,230 @@
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{application::course_schedule::model::Lecture, model::SemesterType};
+
+/// 한 학기에 개설된 과목 정보
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct LectureOffering {
+    year: u32,
+    semester: SemesterType,
+    lectures: Vec<Lecture>,
+}
+
+impl LectureOffering {
+    pub(crate) fn new(year: u32, semester: SemesterType, lectures: Vec<Lecture>) -> Self {
+        Self {
+            year,
+            semester,
+            lectures,
+        }
+    }
+
+    /// 개설 학년도를 반환합니다.
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// 개설 학기를 반환합니다.
+    pub fn semester(&self) -> SemesterType {
+        self.semester
+    }
+
+    /// 해당 학기에 개설된 분반 목록을 반환합니다.
+    pub fn lectures(&self) -> &[Lecture] {
+        &self.lectures
+    }
+
+    /// 해당 학기에 강의한 교수 목록을 중복 없이 정렬하여 반환합니다.
+    pub fn professors(&self) -> Vec<String> {
+        self.lectures
+            .iter()
+            .flat_map(|lecture| lecture.professor().split(','))
+            .map(str::trim)
+            .filter(|professor| !professor.is_empty())
+            .map(str::to_string)
+            .collect::<BTreeSet<String>>()
+            .into_iter()
+            .collect()
+    }
+
+    /// 모든 분반의 수강인원 합계를 반환합니다. 수강인원을 읽을 수 없는 분반은 제외됩니다.
+    pub fn capacity(&self) -> u32 {
+        self.lectures
+            .iter()
+            .filter_map(|lecture| lecture.personeel().trim().parse::<u32>().ok())
+            .sum()
+    }
+}
+
+/// 연속된 두 개설 학기 사이의 변경 사항
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct LectureOfferingChange {
+    from_year: u32,
+    from_semester: SemesterType,
+    to_year: u32,
+    to_semester: SemesterType,
+    added_professors: Vec<String>,
+    removed_professors: Vec<String>,
+    capacity_before: u32,
+    capacity_after: u32,
+}
+
+impl LectureOfferingChange {
+    fn between(before: &LectureOffering, after: &LectureOffering) -> Self {
+        let before_professors = before.professors();
+        let after_professors = after.professors();
+        Self {
+            from_year: before.year,
+            from_semester: before.semester,
+            to_year: after.year,
+            to_semester: after.semester,
+            added_professors: after_professors
+                .iter()
+                .filter(|professor| !before_professors.contains(professor))
+                .cloned()
+                .collect(),
+            removed_professors: before_professors
+                .iter()
+                .filter(|professor| !after_professors.contains(professor))
+                .cloned()
+                .collect(),
+            capacity_before: before.capacity(),
+            capacity_after: after.capacity(),
+        }
+    }
+
+    /// 이전 개설 학년도를 반환합니다.
+    pub fn from_year(&self) -> u32 {
+        self.from_year
+    }
+
+    /// 이전 개설 학기를 반환합니다.
+    pub fn from_semester(&self) -> SemesterType {
+        self.from_semester
+    }
+
+    /// 이후 개설 학년도를 반환합니다.
+    pub fn to_year(&self) -> u32 {
+        self.to_year
+    }
+
+    /// 이후 개설 학기를 반환합니다.
+    pub fn to_semester(&self) -> SemesterType {
+        self.to_semester
+    }
+
+    /// 새로 강의를 맡은 교수 목록을 반환합니다.
+    pub fn added_professors(&self) -> &[String] {
+        &self.added_professors
+    }
+
+    /// 더 이상 강의를 맡지 않은 교수 목록을 반환합니다.
+    pub fn removed_professors(&self) -> &[String] {
+        &self.removed_professors
+    }
+
+    /// 이전 학기의 수강인원 합계를 반환합니다.
+    pub fn capacity_before(&self) -> u32 {
+        self.capacity_before
+    }
+
+    /// 이후 학기의 수강인원 합계를 반환합니다.
+    pub fn capacity_after(&self) -> u32 {
+        self.capacity_after
+    }
+
+    /// 교수 또는 수강인원에 변경이 있는지 여부를 반환합니다.
+    pub fn has_changes(&self) -> bool {
+        !self.added_professors.is_empty()
+            || !self.removed_professors.is_empty()
+            || self.capacity_before != self.capacity_after
+    }
+}
+
+/// 여러 학기에 걸친 과목의 개설 이력
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct LectureHistory {
+    keyword: String,
+    offerings: Vec<LectureOffering>,
+    changes: Vec<LectureOfferingChange>,
+}
+
+impl LectureHistory {
+    /// 검색어와 학기별 개설 정보로 개설 이력을 만듭니다. 개설 정보는 학기 순으로 정렬됩니다.
+    pub(crate) fn new(keyword: &str, mut offerings: Vec<LectureOffering>) -> Self {
+        offerings.sort_by_key(|offering| (offering.year, offering.semester));
+        let changes = offerings
+            .windows(2)
+            .map(|pair| LectureOfferingChange::between(&pair[0], &pair[1]))
+            .collect();
+        Self {
+            keyword: keyword.to_string(),
+            offerings,
+            changes,
+        }
+    }
+
+    /// 검색에 사용한 과목번호 또는 과목명을 반환합니다.
+    pub fn keyword(&self) -> &str {
+        &self.keyword
+    }
+
+    /// 과목이 개설된 학기별 정보를 학기 순으로 반환합니다. 개설되지 않은 학기는 포함되지 않습니다.
+    pub fn offerings(&self) -> &[LectureOffering] {
+        &self.offerings
+    }
+
+    /// 연속된 개설 학기 사이의 교수 및 수강인원 변경 사항을 반환합니다.
+    pub fn changes(&self) -> &[LectureOfferingChange] {
+        &self.changes
+    }
+}
+
+/// 검색 결과 중 검색어와 과목번호가 같거나 과목명에 검색어가 포함된 강의인지 확인합니다.
+pub(crate) fn matches_keyword(lecture: &Lecture, keyword: &str) -> bool {
+    lecture.code() == keyword || lecture.name().contains(keyword)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{LectureHistory, LectureOffering};
+    use crate::{
+        application::course_schedule::model::{self, Lecture},
+        model::SemesterType,
+    };
+
+    fn lecture(professor: &str, personeel: &str) -> Lecture {
+        model::test::lecture(&[("교수명", professor), ("수강인원", personeel)])
+    }
+
+    #[test]
+    fn history_changes() {
+        let history = LectureHistory::new(
+            "21500123",
+            vec![
+                LectureOffering::new(
+                    2024,
+                    SemesterType::Two,
+                    vec![lecture("김철수", "40"), lecture("이영희", "40")],
+                ),
+                LectureOffering::new(2024, SemesterType::One, vec![lecture("홍길동", "60")]),
+                LectureOffering::new(2025, SemesterType::One, vec![lecture("김철수", "80")]),
+            ],
+        );
+        assert_eq!(history.offerings()[0].semester(), SemesterType::One);
+        let changes = history.changes();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].added_professors(), &["김철수", "이영희"]);
+        assert_eq!(changes[0].removed_professors(), &["홍길동"]);
+        assert_eq!(changes[0].capacity_before(), 60);
+        assert_eq!(changes[0].capacity_after(), 80);
+        assert_eq!(changes[1].removed_professors(), &["이영희"]);
+        assert!(changes[1].added_professors().is_empty());
+        assert!(changes[1].has_changes());
+    }
+}
@@ -1,7 +1,11 @@
 use super::{USaintApplication, USaintClient};
+use crate::application::course_schedule::history::{
+    LectureHistory, LectureOffering, matches_keyword,
+};
 use crate::application::course_schedule::utils::{
     combo_box_items, select_lv1, select_lv2, select_tab,
 };
+use crate::application::course_schedule::watcher::LectureWatcher;
 use crate::application::utils::sap_table::try_table_into_with_scroll;
 use crate::application::utils::semester::get_selected_semester;
 use crate::{
@@ -359,12 +363,63 @@ impl<'app> CourseScheduleApplication {
         Ok(lectures.into_iter())
     }
 
+    /// 과목번호 또는 과목명으로 주어진 범위의 학년도(`from_year`부터 `to_year`까지)의 모든 학기를 검색하여 과목의 개설 이력을 반환합니다.
+    ///
+    /// 검색 결과가 없는 학기는 개설되지 않은 것으로 취급하여 이력에서 제외합니다.
+    pub async fn lecture_history(
+        &mut self,
+        keyword: &str,
+        from_year: u32,
+        to_year: u32,
+    ) -> Result<LectureHistory, RusaintError> {
+        let category = LectureCategory::find_by_lecture(keyword);
+        let mut offerings = Vec::new();
+        for year in from_year..=to_year {
+            for semester in [
+                SemesterType::One,
+                SemesterType::Summer,
+                SemesterType::Two,
+                SemesterType::Winter,
+            ] {
+                let lectures: Vec<Lecture> =
+                    match self.find_lectures(year, semester, &category).await {
+                        Ok(lectures) => lectures
+                            .filter(|lecture| matches_keyword(lecture, keyword))
+                            .collect(),
+                        Err(RusaintError::ApplicationError(ApplicationError::NoLectureResult)) => {
+                            continue;
+                        }
+                        Err(err) => return Err(err),
+                    };
+                if !lectures.is_empty() {
+                    offerings.push(LectureOffering::new(year, semester, lectures));
+                }
+            }
+        }
+        Ok(LectureHistory::new(keyword, offerings))
+    }
+
+    /// 주어진 학기의 특정 강의들의 여석과 강의 정보 변경을 감시하는 [`LectureWatcher`]를 만듭니다.
+    pub fn watch_lectures(&mut self, year: u32, semester: SemesterType) -> LectureWatcher<'_> {
+        LectureWatcher::new(self, year, semester)
+    }
+
     // TO-DO: 카테고리 별 선택지 가져오기 기능
 }
 
 #[cfg(test)]
 mod test {}
 
+/// 두 강의 목록 사이의 추가, 삭제, 변경 사항 비교
+pub mod diff;
+/// 여러 학기에 걸친 과목의 개설 이력
+pub mod history;
 /// 강의시간표 애플리케이션에서 사용하는 데이터 모델
 pub mod model;
+/// 강의 목록으로 시간이 겹치지 않는 시간표 조합을 만드는 플래너
+pub mod planner;
+/// 강의 목록을 조건에 따라 걸러내고 정렬하는 질의
+pub mod query;
 mod utils;
+/// 강의의 여석과 강의 정보 변경을 감시하는 감시자
+pub mod watcher;
@@ -5,11 +5,13 @@ use serde::{
     de::{IntoDeserializer, value::MapDeserializer},
 };
 
+use crate::application::personal_course_schedule::model::Weekday;
 use crate::application::utils::de_with::deserialize_optional_string;
 use crate::application::{
     USaintClient,
     course_schedule::utils::{request, request_lv1, request_lv2, request_lv3, request_text},
 };
+use crate::model::TimeOfDay;
 use wdpe::element::parser::ElementParser;
 use wdpe::{
     define_elements,
@@ -405,22 +407,24 @@ impl LectureCategory {
 
 /// 과목 정보
 #[allow(unused)]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 pub struct Lecture {
     /// 계획
     #[serde(
         rename(deserialize = "계획"),
+        alias = "syllabus",
         default,
         deserialize_with = "deserialize_optional_string"
     )]
     syllabus: Option<String>,
     /// 이수구분(주전공)
-    #[serde(rename(deserialize = "이수구분(주전공)"))]
+    #[serde(rename(deserialize = "이수구분(주전공)"), alias = "category")]
     category: String,
     /// 이수구분(다전공)
     #[serde(
         rename(deserialize = "이수구분(다전공)"),
+        alias = "sub_category",
         default,
         deserialize_with = "deserialize_optional_string"
     )]
@@ -428,6 +432,7 @@ pub struct Lecture {
     /// 공학인증
     #[serde(
         rename(deserialize = "공학인증"),
+        alias = "abeek_info",
         default,
         deserialize_with = "deserialize_optional_string"
     )]
@@ -435,46 +440,216 @@ pub struct Lecture {
     /// 교과영역
     #[serde(
         rename(deserialize = "교과영역"),
+        alias = "field",
         default,
         deserialize_with = "deserialize_optional_string"
     )]
     field: Option<String>,
     /// 과목번호
-    #[serde(rename(deserialize = "과목번호"))]
+    #[serde(rename(deserialize = "과목번호"), alias = "code")]
     code: String,
     /// 과목명
-    #[serde(rename(deserialize = "과목명"))]
+    #[serde(rename(deserialize = "과목명"), alias = "name")]
     name: String,
     /// 분반
     #[serde(
         rename(deserialize = "분반"),
+        alias = "division",
         default,
         deserialize_with = "deserialize_optional_string"
     )]
     division: Option<String>,
     /// 교수명
-    #[serde(rename(deserialize = "교수명"))]
+    #[serde(rename(deserialize = "교수명"), alias = "professor")]
     professor: String,
     /// 개설학과
-    #[serde(rename(deserialize = "개설학과"))]
+    #[serde(rename(deserialize = "개설학과"), alias = "department")]
     department: String,
     /// 시간/학점(설계)
-    #[serde(rename(deserialize = "시간/학점(설계)"))]
+    #[serde(rename(deserialize = "시간/학점(설계)"), alias = "time_points")]
     time_points: String,
     /// 수강인원
-    #[serde(rename(deserialize = "수강인원"))]
+    #[serde(rename(deserialize = "수강인원"), alias = "personeel")]
     personeel: String,
     /// 여석
-    #[serde(rename(deserialize = "여석"))]
+    #[serde(rename(deserialize = "여석"), alias = "remaining_seats")]
     remaining_seats: String,
     /// 강의시간(강의실)
-    #[serde(rename(deserialize = "강의시간(강의실)"))]
+    #[serde(rename(deserialize = "강의시간(강의실)"), alias = "schedule_room")]
     schedule_room: String,
     /// 수강대상
-    #[serde(rename(deserialize = "수강대상"))]
+    #[serde(rename(deserialize = "수강대상"), alias = "target")]
     target: String,
 }
 
+impl Lecture {
+    /// 강의계획서 정보를 반환합니다.
+    pub fn syllabus(&self) -> Option<&str> {
+        self.syllabus.as_deref()
+    }
+
+    /// 이수구분(주전공)을 반환합니다.
+    pub fn category(&self) -> &str {
+        &self.category
+    }
+
+    /// 이수구분(다전공)을 반환합니다.
+    pub fn sub_category(&self) -> Option<&str> {
+        self.sub_category.as_deref()
+    }
+
+    /// 공학인증 정보를 반환합니다.
+    pub fn abeek_info(&self) -> Option<&str> {
+        self.abeek_info.as_deref()
+    }
+
+    /// 교과영역을 반환합니다.
+    pub fn field(&self) -> Option<&str> {
+        self.field.as_deref()
+    }
+
+    /// 과목번호를 반환합니다.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// 과목명을 반환합니다.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 분반을 반환합니다.
+    pub fn division(&self) -> Option<&str> {
+        self.division.as_deref()
+    }
+
+    /// 교수명을 반환합니다.
+    pub fn professor(&self) -> &str {
+        &self.professor
+    }
+
+    /// 개설학과를 반환합니다.
+    pub fn department(&self) -> &str {
+        &self.department
+    }
+
+    /// 시간/학점(설계)을 반환합니다.
+    pub fn time_points(&self) -> &str {
+        &self.time_points
+    }
+
+    /// 수강인원을 반환합니다.
+    pub fn personeel(&self) -> &str {
+        &self.personeel
+    }
+
+    /// 여석을 반환합니다.
+    pub fn remaining_seats(&self) -> &str {
+        &self.remaining_seats
+    }
+
+    /// 강의시간(강의실)을 반환합니다.
+    pub fn schedule_room(&self) -> &str {
+        &self.schedule_room
+    }
+
+    /// 수강대상을 반환합니다.
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    /// 시간/학점(설계)에서 학점을 읽어 반환합니다. 학점을 읽을 수 없다면 `None`을 반환합니다.
+    pub fn credits(&self) -> Option<f32> {
+        let (_, points) = self.time_points.split_once('/')?;
+        points
+            .split('(')
+            .next()
+            .and_then(|str| str.trim().parse().ok())
+    }
+
+    /// 강의시간(강의실)을 요일별 강의 시간 목록으로 변환합니다. 읽을 수 없는 형식의 시간은 무시됩니다.
+    pub fn schedules(&self) -> Vec<LectureTime> {
+        self.schedule_room
+            .lines()
+            .flat_map(LectureTime::from_line)
+            .collect()
+    }
+}
+
+/// 강의의 요일별 시간 정보
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct LectureTime {
+    weekday: Weekday,
+    start: TimeOfDay,
+    end: TimeOfDay,
+    place: Option<String>,
+}
+
+impl LectureTime {
+    /// 새로운 [`LectureTime`]을 만듭니다.
+    pub fn new(weekday: Weekday, start: TimeOfDay, end: TimeOfDay, place: Option<&str>) -> Self {
+        Self {
+            weekday,
+            start,
+            end,
+            place: place.map(str::to_string),
+        }
+    }
+
+    /// `월 수 10:30-11:45 (정보과학관 21203-홍길동)` 형태의 문자열 한 줄을 요일별 시간으로 변환합니다.
+    fn from_line(line: &str) -> Vec<LectureTime> {
+        let mut tokens = line.split_whitespace().peekable();
+        let mut weekdays = Vec::new();
+        while let Some(weekday) = tokens.peek().and_then(|str| Weekday::from_korean(str)) {
+            weekdays.push(weekday);
+            tokens.next();
+        }
+        let Some((start, end)) = tokens.next().and_then(|str| str.split_once('-')) else {
+            return Vec::with_capacity(0);
+        };
+        let (Ok(start), Ok(end)) = (start.parse::<TimeOfDay>(), end.parse::<TimeOfDay>()) else {
+            return Vec::with_capacity(0);
+        };
+        let place = tokens.collect::<Vec<&str>>().join(" ");
+        let place = place
+            .trim()
+            .trim_start_matches('(')
+            .trim_end_matches(')')
+            .trim();
+        let place = (!place.is_empty()).then_some(place);
+        weekdays
+            .into_iter()
+            .map(|weekday| LectureTime::new(weekday, start, end, place))
+            .collect()
+    }
+
+    /// 요일을 반환합니다.
+    pub fn weekday(&self) -> Weekday {
+        self.weekday
+    }
+
+    /// 시작 시각을 반환합니다.
+    pub fn start(&self) -> TimeOfDay {
+        self.start
+    }
+
+    /// 종료 시각을 반환합니다.
+    pub fn end(&self) -> TimeOfDay {
+        self.end
+    }
+
+    /// 강의실 정보를 반환합니다.
+    pub fn place(&self) -> Option<&str> {
+        self.place.as_deref()
+    }
+
+    /// 주어진 요일과 시간에 이 강의 시간이 겹치는지 확인합니다.
+    pub fn overlaps(&self, weekday: Weekday, start: TimeOfDay, end: TimeOfDay) -> bool {
+        self.weekday == weekday && self.start < end && start < self.end
+    }
+}
+
 impl<'body> FromSapTable<'body> for Lecture {
     fn from_table(
         header: &'body wdpe::element::complex::sap_table::SapTableHeader,
@@ -587,3 +762,33 @@ impl Default for LectureCategoryBuilder {
         Self::new()
     }
 }
+
+#[cfg(test)]
+pub(crate) mod test {
+    use super::Lecture;
+    use crate::application::utils::from_fields;
+
+    /// 기본 과목 정보에 주어진 필드를 덮어쓴 테스트용 [`Lecture`]를 만듭니다.
+    pub(crate) fn lecture(fields: &[(&str, &str)]) -> Lecture {
+        let mut merged = vec![
+            ("이수구분(주전공)", "전필-컴퓨터"),
+            ("과목번호", "21500123"),
+            ("과목명", "자료구조"),
+            ("분반", "01"),
+            ("교수명", "홍길동"),
+            ("개설학과", "컴퓨터학부"),
+            ("시간/학점(설계)", "3.0 /3.0"),
+            ("수강인원", "40"),
+            ("여석", "0"),
+            ("강의시간(강의실)", "월 09:00-10:15"),
+            ("수강대상", "전체"),
+        ];
+        for (key, value) in fields {
+            match merged.iter_mut().find(|(other, _)| other == key) {
+                Some(field) => field.1 = value,
+                None => merged.push((key, value)),
+            }
+        }
+        from_fields(&merged)
+    }
+}
@@ -0,0 +1,469 @@
+use std::{cmp::Ordering, collections::BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ApplicationError, RusaintError,
+    application::{
+        course_schedule::model::{Lecture, LectureTime},
+        personal_course_schedule::model::Weekday,
+    },
+    model::TimeOfDay,
+};
+
+/// 시간표에 반드시 비워두어야 하는 개인 일정
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Commitment {
+    name: String,
+    weekday: Weekday,
+    start: TimeOfDay,
+    end: TimeOfDay,
+}
+
+impl Commitment {
+    /// 새로운 [`Commitment`]를 만듭니다.
+    pub fn new(name: &str, weekday: Weekday, start: TimeOfDay, end: TimeOfDay) -> Self {
+        Self {
+            name: name.to_string(),
+            weekday,
+            start,
+            end,
+        }
+    }
+
+    /// 일정 이름을 반환합니다.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 일정의 요일을 반환합니다.
+    pub fn weekday(&self) -> Weekday {
+        self.weekday
+    }
+
+    /// 일정의 시작 시각을 반환합니다.
+    pub fn start(&self) -> TimeOfDay {
+        self.start
+    }
+
+    /// 일정의 종료 시각을 반환합니다.
+    pub fn end(&self) -> TimeOfDay {
+        self.end
+    }
+}
+
+/// 시간이 겹치지 않는 분반 조합으로 만들어진 시간표
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Timetable {
+    lectures: Vec<Lecture>,
+    credits: f32,
+    days: Vec<Weekday>,
+    idle_minutes: u32,
+    earliest_start: Option<TimeOfDay>,
+}
+
+impl Timetable {
+    fn new(lectures: Vec<Lecture>) -> Self {
+        let credits = lectures.iter().filter_map(Lecture::credits).sum();
+        let mut times: Vec<LectureTime> = lectures.iter().flat_map(Lecture::schedules).collect();
+        times.sort_by_key(|time| (time.weekday(), time.start()));
+        let days: Vec<Weekday> = times
+            .iter()
+            .map(LectureTime::weekday)
+            .collect::<BTreeSet<Weekday>>()
+            .into_iter()
+            .collect();
+        let idle_minutes = times
+            .windows(2)
+            .filter(|pair| pair[0].weekday() == pair[1].weekday())
+            .map(|pair| {
+                pair[1]
+                    .start()
+                    .minutes_from_midnight()
+                    .saturating_sub(pair[0].end().minutes_from_midnight())
+            })
+            .sum();
+        let earliest_start = times.iter().map(LectureTime::start).min();
+        Self {
+            lectures,
+            credits,
+            days,
+            idle_minutes,
+            earliest_start,
+        }
+    }
+
+    /// 시간표에 포함된 강의(분반) 목록을 반환합니다.
+    pub fn lectures(&self) -> &[Lecture] {
+        &self.lectures
+    }
+
+    /// 시간표의 총 학점을 반환합니다.
+    pub fn credits(&self) -> f32 {
+        self.credits
+    }
+
+    /// 수업이 있는 요일 목록을 반환합니다.
+    pub fn days(&self) -> &[Weekday] {
+        &self.days
+    }
+
+    /// 같은 날 수업 사이의 공강 시간(분)의 합을 반환합니다.
+    pub fn idle_minutes(&self) -> u32 {
+        self.idle_minutes
+    }
+
+    /// 가장 이른 수업의 시작 시각을 반환합니다.
+    pub fn earliest_start(&self) -> Option<TimeOfDay> {
+        self.earliest_start
+    }
+
+    fn rank(&self, other: &Self) -> Ordering {
+        self.days
+            .len()
+            .cmp(&other.days.len())
+            .then(self.idle_minutes.cmp(&other.idle_minutes))
+            .then(other.earliest_start.cmp(&self.earliest_start))
+    }
+}
+
+/// 수강 희망 과목 목록으로 시간이 겹치지 않는 시간표 조합을 만드는 플래너
+///
+/// ### 예시
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// # use std::sync::Arc;
+/// # use rusaint::USaintSession;
+/// # use rusaint::application::USaintClientBuilder;
+/// # use rusaint::application::course_schedule::{CourseScheduleApplication, model::LectureCategory, planner::TimetablePlanner};
+/// # use rusaint::application::personal_course_schedule::model::Weekday;
+/// # use rusaint::model::{SemesterType, TimeOfDay};
+/// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+/// let mut app = USaintClientBuilder::new().session(session).build_into::<CourseScheduleApplication>().await.unwrap();
+/// let category = LectureCategory::major("IT대학", "컴퓨터학부", None);
+/// let lectures = app.find_lectures(2025, SemesterType::One, &category).await.unwrap();
+/// let timetables = TimetablePlanner::new(lectures)
+///     .course("21500123")
+///     .course("21500456")
+///     .free_day(Weekday::Fri)
+///     .earliest_start(TimeOfDay::new(10, 0).unwrap())
+///     .plan()
+///     .unwrap();
+/// println!("{:?}", timetables.first());
+/// # })
+/// ```
+#[derive(Clone, Debug)]
+pub struct TimetablePlanner {
+    lectures: Vec<Lecture>,
+    courses: Vec<String>,
+    commitments: Vec<Commitment>,
+    max_credits: Option<f32>,
+    free_days: Vec<Weekday>,
+    earliest_start: Option<TimeOfDay>,
+    limit: usize,
+}
+
+impl TimetablePlanner {
+    /// 후보가 되는 강의 목록으로 새로운 플래너를 만듭니다.
+    pub fn new(lectures: impl IntoIterator<Item = Lecture>) -> Self {
+        Self {
+            lectures: lectures.into_iter().collect(),
+            courses: Vec::new(),
+            commitments: Vec::new(),
+            max_credits: None,
+            free_days: Vec::new(),
+            earliest_start: None,
+            limit: 20,
+        }
+    }
+
+    /// 반드시 시간표에 포함할 과목번호를 추가합니다.
+    pub fn course(mut self, code: &str) -> Self {
+        if !self.courses.iter().any(|course| course == code) {
+            self.courses.push(code.to_string());
+        }
+        self
+    }
+
+    /// 시간표에서 비워두어야 하는 개인 일정을 추가합니다.
+    pub fn commitment(mut self, commitment: Commitment) -> Self {
+        self.commitments.push(commitment);
+        self
+    }
+
+    /// 시간표의 최대 학점을 지정합니다.
+    pub fn max_credits(mut self, credits: f32) -> Self {
+        self.max_credits = Some(credits);
+        self
+    }
+
+    /// 수업이 없어야 하는 요일을 추가합니다.
+    pub fn free_day(mut self, weekday: Weekday) -> Self {
+        self.free_days.push(weekday);
+        self
+    }
+
+    /// 수업이 시작할 수 있는 가장 이른 시각을 지정합니다.
+    pub fn earliest_start(mut self, time: TimeOfDay) -> Self {
+        self.earliest_start = Some(time);
+        self
+    }
+
+    /// 반환할 시간표의 최대 개수를 지정합니다. 기본값은 20입니다.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    fn fits_constraints(&self, lecture: &Lecture) -> bool {
+        lecture.schedules().iter().all(|time| {
+            !self.free_days.contains(&time.weekday())
+                && self
+                    .earliest_start
+                    .is_none_or(|earliest| time.start() >= earliest)
+                && !self.commitments.iter().any(|commitment| {
+                    time.overlaps(commitment.weekday, commitment.start, commitment.end)
+                })
+        })
+    }
+
+    /// 조건에 맞는 시간표 조합을 순위대로 반환합니다.
+    ///
+    /// 시간표는 수업이 있는 요일 수가 적을수록, 공강 시간이 짧을수록, 첫 수업이 늦게 시작할수록 앞에 위치합니다.
+    /// 모든 조합을 탐색하되, 이미 찾은 상위 시간표보다 수업 요일이 많아지는 조합은 더 탐색하지 않습니다.
+    /// 희망 과목이 없다면 빈 목록을, 희망 과목 중 조건을 만족하는 분반이 하나도 없는 과목이 있다면 [`ApplicationError::NoLectureResult`]를 반환합니다.
+    pub fn plan(&self) -> Result<Vec<Timetable>, RusaintError> {
+        if self.courses.is_empty() || self.limit == 0 {
+            return Ok(Vec::new());
+        }
+        let mut candidates: Vec<Vec<Candidate>> = Vec::with_capacity(self.courses.len());
+        for code in &self.courses {
+            let divisions: Vec<Candidate> = self
+                .lectures
+                .iter()
+                .filter(|lecture| lecture.code() == code && self.fits_constraints(lecture))
+                .map(|lecture| Candidate {
+                    lecture,
+                    times: lecture.schedules(),
+                })
+                .collect();
+            if divisions.is_empty() {
+                return Err(ApplicationError::NoLectureResult.into());
+            }
+            candidates.push(divisions);
+        }
+        // 분반 수가 적은 과목부터 선택하여 탐색 공간을 줄입니다.
+        candidates.sort_by_key(Vec::len);
+        let mut best = Vec::with_capacity(self.limit + 1);
+        self.search(&candidates, &mut Vec::new(), &mut best);
+        Ok(best)
+    }
+
+    fn search<'a>(
+        &self,
+        candidates: &'a [Vec<Candidate<'a>>],
+        selected: &mut Vec<&'a Candidate<'a>>,
+        best: &mut Vec<Timetable>,
+    ) {
+        let Some((divisions, rest)) = candidates.split_first() else {
+            let timetable = Timetable::new(
+                selected
+                    .iter()
+                    .map(|candidate| candidate.lecture.clone())
+                    .collect(),
+            );
+            let index = best.partition_point(|other| other.rank(&timetable) != Ordering::Greater);
+            if index < self.limit {
+                best.insert(index, timetable);
+                best.truncate(self.limit);
+            }
+            return;
+        };
+        // 수업 요일 수는 과목을 추가할수록 줄어들지 않으므로, 상위 시간표보다 많아진 조합은 제외합니다.
+        if best.len() >= self.limit {
+            let days: BTreeSet<Weekday> = selected
+                .iter()
+                .flat_map(|candidate| candidate.times.iter().map(LectureTime::weekday))
+                .collect();
+            if best
+                .last()
+                .is_some_and(|worst| days.len() > worst.days.len())
+            {
+                return;
+            }
+        }
+        let selected_credits: f32 = selected
+            .iter()
+            .filter_map(|candidate| candidate.lecture.credits())
+            .sum();
+        for candidate in divisions {
+            let credits = selected_credits + candidate.lecture.credits().unwrap_or(0.0);
+            if self.max_credits.is_some_and(|max| credits > max) {
+                continue;
+            }
+            if selected.iter().any(|other| candidate.conflicts(other)) {
+                continue;
+            }
+            selected.push(candidate);
+            self.search(rest, selected, best);
+            selected.pop();
+        }
+    }
+}
+
+/// 강의 시간을 미리 변환해 둔 분반 후보
+struct Candidate<'a> {
+    lecture: &'a Lecture,
+    times: Vec<LectureTime>,
+}
+
+impl Candidate<'_> {
+    fn conflicts(&self, other: &Candidate) -> bool {
+        self.times.iter().any(|time| {
+            other
+                .times
+                .iter()
+                .any(|other| time.overlaps(other.weekday(), other.start(), other.end()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Commitment, TimetablePlanner};
+    use crate::{
+        ApplicationError, RusaintError,
+        application::{
+            course_schedule::model::{self, Lecture},
+            personal_course_schedule::model::Weekday,
+        },
+        model::TimeOfDay,
+    };
+
+    fn lecture(code: &str, division: &str, credits: &str, schedule_room: &str) -> Lecture {
+        model::test::lecture(&[
+            ("과목번호", code),
+            ("과목명", code),
+            ("분반", division),
+            ("시간/학점(설계)", credits),
+            ("강의시간(강의실)", schedule_room),
+        ])
+    }
+
+    fn time(hour: u32, minute: u32) -> TimeOfDay {
+        TimeOfDay::new(hour, minute).unwrap()
+    }
+
+    #[test]
+    fn parse_lecture_schedules() {
+        let lecture = lecture(
+            "A",
+            "01",
+            "3.0 /3.0 (0)",
+            "월 수 10:30-11:45 (정보과학관 21203-홍길동)\n금 09:00-09:50",
+        );
+        let schedules = lecture.schedules();
+        assert_eq!(schedules.len(), 3);
+        assert_eq!(schedules[0].weekday(), Weekday::Mon);
+        assert_eq!(schedules[1].weekday(), Weekday::Wed);
+        assert_eq!(schedules[1].start(), time(10, 30));
+        assert_eq!(schedules[1].place(), Some("정보과학관 21203-홍길동"));
+        assert_eq!(schedules[2].place(), None);
+        assert_eq!(lecture.credits(), Some(3.0));
+    }
+
+    #[test]
+    fn plan_without_conflicts() {
+        let lectures = vec![
+            lecture("A", "01", "3.0 /3.0", "월 09:00-10:15"),
+            lecture("A", "02", "3.0 /3.0", "화 09:00-10:15"),
+            lecture("B", "01", "3.0 /3.0", "월 09:30-10:45"),
+            lecture("B", "02", "3.0 /3.0", "월 10:30-11:45"),
+        ];
+        let timetables = TimetablePlanner::new(lectures)
+            .course("A")
+            .course("B")
+            .plan()
+            .unwrap();
+        assert_eq!(timetables.len(), 3);
+        // 모든 수업이 월요일에 있는 조합이 가장 앞에 위치합니다.
+        let first = &timetables[0];
+        assert_eq!(first.days(), &[Weekday::Mon]);
+        assert_eq!(first.credits(), 6.0);
+        assert_eq!(first.idle_minutes(), 15);
+    }
+
+    #[test]
+    fn plan_with_constraints() {
+        let lectures = vec![
+            lecture("A", "01", "3.0 /3.0", "월 09:00-10:15"),
+            lecture("A", "02", "3.0 /3.0", "화 13:00-14:15"),
+            lecture("B", "01", "3.0 /3.0", "수 12:00-13:15"),
+            lecture("B", "02", "3.0 /3.0", "목 15:00-16:15"),
+        ];
+        let timetables = TimetablePlanner::new(lectures.clone())
+            .course("A")
+            .course("B")
+            .earliest_start(time(10, 0))
+            .free_day(Weekday::Thu)
+            .plan()
+            .unwrap();
+        assert_eq!(timetables.len(), 1);
+        let divisions: Vec<(&str, Option<&str>)> = timetables[0]
+            .lectures()
+            .iter()
+            .map(|lecture| (lecture.code(), lecture.division()))
+            .collect();
+        assert_eq!(divisions, vec![("A", Some("02")), ("B", Some("01"))]);
+        let err = TimetablePlanner::new(lectures.clone())
+            .course("A")
+            .commitment(Commitment::new(
+                "아르바이트",
+                Weekday::Mon,
+                time(8, 0),
+                time(12, 0),
+            ))
+            .commitment(Commitment::new(
+                "동아리",
+                Weekday::Tue,
+                time(13, 0),
+                time(14, 0),
+            ))
+            .plan()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            RusaintError::ApplicationError(ApplicationError::NoLectureResult)
+        ));
+        let timetables = TimetablePlanner::new(lectures)
+            .course("A")
+            .course("B")
+            .max_credits(3.0)
+            .plan()
+            .unwrap();
+        assert!(timetables.is_empty());
+    }
+
+    #[test]
+    fn plan_best_timetables_within_limit() {
+        let lectures = vec![
+            lecture("A", "01", "3.0 /3.0", "화 09:00-10:15"),
+            lecture("A", "02", "3.0 /3.0", "수 09:00-10:15"),
+            lecture("A", "03", "3.0 /3.0", "월 09:00-10:15"),
+            lecture("B", "01", "3.0 /3.0", "목 09:00-10:15"),
+            lecture("B", "02", "3.0 /3.0", "월 13:00-14:15"),
+            lecture("B", "03", "3.0 /3.0", "월 10:30-11:45"),
+        ];
+        let timetables = TimetablePlanner::new(lectures.clone())
+            .course("A")
+            .course("B")
+            .limit(2)
+            .plan()
+            .unwrap();
+        // 탐색 순서와 관계없이 가장 좋은 조합이 반환됩니다.
+        assert_eq!(timetables.len(), 2);
+        assert_eq!(timetables[0].idle_minutes(), 15);
+        assert_eq!(timetables[1].idle_minutes(), 165);
+        assert!(TimetablePlanner::new(lectures).plan().unwrap().is_empty());
+    }
+}
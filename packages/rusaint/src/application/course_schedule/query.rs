@@ -0,0 +1,353 @@
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    application::{
+        course_schedule::model::{Lecture, LectureTime},
+        personal_course_schedule::model::Weekday,
+    },
+    model::TimeOfDay,
+};
+
+/// 강의 목록을 정렬할 때 사용하는 기준
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum LectureSortKey {
+    /// 과목번호, 분반 순
+    Code,
+    /// 과목명
+    Name,
+    /// 교수명
+    Professor,
+    /// 학점
+    Credits,
+    /// 여석
+    RemainingSeats,
+    /// 가장 이른 수업 시작 시각
+    EarliestStart,
+}
+
+/// 강의 목록을 조건에 따라 걸러내고 정렬하는 질의
+///
+/// 지정하지 않은 조건은 검사하지 않으며, 지정한 모든 조건을 만족하는 강의만 남깁니다.
+/// ### 예시
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// # use std::sync::Arc;
+/// # use rusaint::USaintSession;
+/// # use rusaint::application::USaintClientBuilder;
+/// # use rusaint::application::course_schedule::{CourseScheduleApplication, model::LectureCategory, query::{LectureQuery, LectureSortKey}};
+/// # use rusaint::application::personal_course_schedule::model::Weekday;
+/// # use rusaint::model::SemesterType;
+/// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+/// let mut app = USaintClientBuilder::new().session(session).build_into::<CourseScheduleApplication>().await.unwrap();
+/// let category = LectureCategory::major("IT대학", "컴퓨터학부", None);
+/// let lectures = app.find_lectures(2025, SemesterType::One, &category).await.unwrap();
+/// let lectures = LectureQuery::new()
+///     .min_remaining_seats(1)
+///     .weekdays(&[Weekday::Mon, Weekday::Wed])
+///     .sort_by(LectureSortKey::RemainingSeats, true)
+///     .apply(lectures);
+/// println!("{:?}", lectures);
+/// # })
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct LectureQuery {
+    /// 교수명에 포함되어야 하는 문자열
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    professor: Option<String>,
+    /// 최소 학점
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    min_credits: Option<f32>,
+    /// 최대 학점
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    max_credits: Option<f32>,
+    /// 수업이 있어도 되는 요일 목록. 비어 있다면 요일을 검사하지 않습니다.
+    #[cfg_attr(feature = "uniffi", uniffi(default = []))]
+    weekdays: Vec<Weekday>,
+    /// 수업이 시작할 수 있는 가장 이른 시각
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    start_after: Option<TimeOfDay>,
+    /// 수업이 끝나야 하는 가장 늦은 시각
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    end_before: Option<TimeOfDay>,
+    /// 최소 여석
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    min_remaining_seats: Option<u32>,
+    /// 공학인증 여부
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    abeek: Option<bool>,
+    /// 정렬 기준
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    sort_key: Option<LectureSortKey>,
+    /// 내림차순 정렬 여부
+    #[cfg_attr(feature = "uniffi", uniffi(default = false))]
+    descending: bool,
+}
+
+impl LectureQuery {
+    /// 아무 조건도 없는 새로운 [`LectureQuery`]를 만듭니다.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 교수명에 주어진 문자열이 포함된 강의만 남깁니다.
+    pub fn professor(mut self, professor: &str) -> Self {
+        self.professor = Some(professor.to_string());
+        self
+    }
+
+    /// 학점이 주어진 값 이상인 강의만 남깁니다.
+    pub fn min_credits(mut self, credits: f32) -> Self {
+        self.min_credits = Some(credits);
+        self
+    }
+
+    /// 학점이 주어진 값 이하인 강의만 남깁니다.
+    pub fn max_credits(mut self, credits: f32) -> Self {
+        self.max_credits = Some(credits);
+        self
+    }
+
+    /// 모든 수업이 주어진 요일에만 있는 강의만 남깁니다.
+    pub fn weekdays(mut self, weekdays: &[Weekday]) -> Self {
+        self.weekdays = weekdays.to_vec();
+        self
+    }
+
+    /// 모든 수업이 주어진 시각 이후에 시작하는 강의만 남깁니다.
+    pub fn start_after(mut self, time: TimeOfDay) -> Self {
+        self.start_after = Some(time);
+        self
+    }
+
+    /// 모든 수업이 주어진 시각 이전에 끝나는 강의만 남깁니다.
+    pub fn end_before(mut self, time: TimeOfDay) -> Self {
+        self.end_before = Some(time);
+        self
+    }
+
+    /// 여석이 주어진 값 이상인 강의만 남깁니다.
+    pub fn min_remaining_seats(mut self, seats: u32) -> Self {
+        self.min_remaining_seats = Some(seats);
+        self
+    }
+
+    /// 공학인증 여부가 주어진 값과 같은 강의만 남깁니다.
+    pub fn abeek(mut self, abeek: bool) -> Self {
+        self.abeek = Some(abeek);
+        self
+    }
+
+    /// 결과를 정렬할 기준을 지정합니다. 기준 값이 없는 강의는 항상 뒤에 위치합니다.
+    pub fn sort_by(mut self, key: LectureSortKey, descending: bool) -> Self {
+        self.sort_key = Some(key);
+        self.descending = descending;
+        self
+    }
+
+    /// 강의가 이 질의의 모든 조건을 만족하는지 확인합니다.
+    ///
+    /// 학점, 여석, 요일, 시각 조건이 지정되었을 때 해당 값을 읽을 수 없는 강의는 조건을 만족하지 않는 것으로 취급합니다.
+    pub fn matches(&self, lecture: &Lecture) -> bool {
+        if let Some(professor) = &self.professor {
+            if !lecture.professor().contains(professor.as_str()) {
+                return false;
+            }
+        }
+        if self.min_credits.is_some() || self.max_credits.is_some() {
+            let Some(credits) = lecture.credits() else {
+                return false;
+            };
+            if self.min_credits.is_some_and(|min| credits < min)
+                || self.max_credits.is_some_and(|max| credits > max)
+            {
+                return false;
+            }
+        }
+        if let Some(min) = self.min_remaining_seats {
+            if remaining_seats(lecture).is_none_or(|seats| seats < min) {
+                return false;
+            }
+        }
+        if let Some(abeek) = self.abeek {
+            if is_abeek(lecture) != abeek {
+                return false;
+            }
+        }
+        let schedules = lecture.schedules();
+        if schedules.is_empty() {
+            return !self.has_time_filter();
+        }
+        schedules.iter().all(|time| self.matches_time(time))
+    }
+
+    fn has_time_filter(&self) -> bool {
+        !self.weekdays.is_empty() || self.start_after.is_some() || self.end_before.is_some()
+    }
+
+    fn matches_time(&self, time: &LectureTime) -> bool {
+        (self.weekdays.is_empty() || self.weekdays.contains(&time.weekday()))
+            && self.start_after.is_none_or(|start| time.start() >= start)
+            && self.end_before.is_none_or(|end| time.end() <= end)
+    }
+
+    /// 조건을 만족하는 강의만 남기고, 정렬 기준이 있다면 정렬하여 반환합니다.
+    pub fn apply(&self, lectures: impl IntoIterator<Item = Lecture>) -> Vec<Lecture> {
+        let mut lectures: Vec<Lecture> = lectures
+            .into_iter()
+            .filter(|lecture| self.matches(lecture))
+            .collect();
+        if let Some(key) = self.sort_key {
+            lectures.sort_by(|a, b| self.compare(key, a, b));
+        }
+        lectures
+    }
+
+    fn compare(&self, key: LectureSortKey, a: &Lecture, b: &Lecture) -> Ordering {
+        let ordering = match key {
+            LectureSortKey::Code => (a.code(), a.division()).cmp(&(b.code(), b.division())),
+            LectureSortKey::Name => a.name().cmp(b.name()),
+            LectureSortKey::Professor => a.professor().cmp(b.professor()),
+            LectureSortKey::Credits => {
+                return compare_optional(a.credits(), b.credits(), self.descending);
+            }
+            LectureSortKey::RemainingSeats => {
+                return compare_optional(remaining_seats(a), remaining_seats(b), self.descending);
+            }
+            LectureSortKey::EarliestStart => {
+                return compare_optional(earliest_start(a), earliest_start(b), self.descending);
+            }
+        };
+        if self.descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    }
+}
+
+/// 값이 없는 항목을 정렬 방향과 관계 없이 뒤에 위치시키며 비교합니다.
+fn compare_optional<T: PartialOrd>(a: Option<T>, b: Option<T>, descending: bool) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            let ordering = a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+            if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        }
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+fn remaining_seats(lecture: &Lecture) -> Option<u32> {
+    lecture.remaining_seats().trim().parse().ok()
+}
+
+fn earliest_start(lecture: &Lecture) -> Option<TimeOfDay> {
+    lecture.schedules().iter().map(LectureTime::start).min()
+}
+
+/// `공학주제-소프트공인증/인필-소프트공인증`과 같이 `이수구분-프로그램명인증` 형태의 항목이 하나라도 있는지 확인합니다.
+fn is_abeek(lecture: &Lecture) -> bool {
+    lecture.abeek_info().is_some_and(|info| {
+        info.split('/').any(|entry| {
+            entry.split_once('-').is_some_and(|(category, program)| {
+                !category.trim().is_empty() && program.trim().ends_with("인증")
+            })
+        })
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{LectureQuery, LectureSortKey};
+    use crate::{
+        application::{
+            course_schedule::model::{self, Lecture},
+            personal_course_schedule::model::Weekday,
+        },
+        model::TimeOfDay,
+    };
+
+    fn lecture(
+        code: &str,
+        professor: &str,
+        credits: &str,
+        remaining_seats: &str,
+        abeek_info: &str,
+        schedule_room: &str,
+    ) -> Lecture {
+        model::test::lecture(&[
+            ("공학인증", abeek_info),
+            ("과목번호", code),
+            ("과목명", code),
+            ("교수명", professor),
+            ("시간/학점(설계)", credits),
+            ("여석", remaining_seats),
+            ("강의시간(강의실)", schedule_room),
+        ])
+    }
+
+    fn lectures() -> Vec<Lecture> {
+        vec![
+            lecture(
+                "A",
+                "홍길동",
+                "3.0 /3.0",
+                "5",
+                "공학주제-소프트공인증/인필-소프트공인증",
+                "월 09:00-10:15",
+            ),
+            lecture("B", "김철수", "2.0 /2.0", "0", "", "화 13:00-14:50"),
+            lecture("C", "홍길동", "3.0 /3.0", "12", "-", "월 수 10:30-11:45"),
+            lecture("D", "이영희", "1.0 /1.0", "", "", "금 18:00-18:50"),
+            lecture("E", "박민수", "3.0 /3.0", "3", "", "추후공지"),
+        ]
+    }
+
+    fn codes(lectures: &[Lecture]) -> Vec<&str> {
+        lectures.iter().map(Lecture::code).collect()
+    }
+
+    #[test]
+    fn filter_lectures() {
+        let query = LectureQuery::new().professor("홍길동").min_credits(3.0);
+        assert_eq!(codes(&query.apply(lectures())), vec!["A", "C"]);
+
+        let query = LectureQuery::new().weekdays(&[Weekday::Mon, Weekday::Tue, Weekday::Wed]);
+        assert_eq!(codes(&query.apply(lectures())), vec!["A", "B", "C"]);
+
+        let query = LectureQuery::new()
+            .start_after(TimeOfDay::new(10, 0).unwrap())
+            .end_before(TimeOfDay::new(15, 0).unwrap());
+        assert_eq!(codes(&query.apply(lectures())), vec!["B", "C"]);
+
+        let query = LectureQuery::new().min_remaining_seats(1).abeek(false);
+        assert_eq!(codes(&query.apply(lectures())), vec!["C", "E"]);
+
+        let query = LectureQuery::new().abeek(true);
+        assert_eq!(codes(&query.apply(lectures())), vec!["A"]);
+    }
+
+    #[test]
+    fn sort_lectures() {
+        let query = LectureQuery::new().sort_by(LectureSortKey::RemainingSeats, true);
+        assert_eq!(
+            codes(&query.apply(lectures())),
+            vec!["C", "A", "E", "B", "D"]
+        );
+
+        let query = LectureQuery::new().sort_by(LectureSortKey::EarliestStart, false);
+        assert_eq!(
+            codes(&query.apply(lectures())),
+            vec!["A", "C", "B", "D", "E"]
+        );
+    }
+}
@@ -0,0 +1,456 @@
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+
+use futures_util::{Stream, stream};
+use serde::{Deserialize, Serialize};
+use tokio::time::{Instant, sleep_until};
+
+use crate::{
+    ApplicationError, RusaintError,
+    application::{
+        course_schedule::{
+            CourseScheduleApplication,
+            model::{Lecture, LectureCategory},
+        },
+        personal_course_schedule::model::Weekday,
+    },
+    model::{SemesterType, TimeOfDay},
+};
+
+/// 감시 중인 강의에서 발생한 변경 사항
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum LectureChangeEvent {
+    /// 여석이 없던 강의에 여석이 생김
+    SeatsOpened {
+        /// 과목번호
+        code: String,
+        /// 분반
+        division: Option<String>,
+        /// 현재 여석
+        remaining_seats: u32,
+    },
+    /// 여석이 있던 강의의 여석이 모두 참
+    SeatsFilled {
+        /// 과목번호
+        code: String,
+        /// 분반
+        division: Option<String>,
+    },
+    /// 여석이 있는 상태에서 여석 수가 변경됨
+    SeatsChanged {
+        /// 과목번호
+        code: String,
+        /// 분반
+        division: Option<String>,
+        /// 이전 여석
+        before: u32,
+        /// 현재 여석
+        after: u32,
+    },
+    /// 이전 조회에서 없던 강의가 새로 조회됨(분반 추가 등)
+    Added {
+        /// 과목번호
+        code: String,
+        /// 분반
+        division: Option<String>,
+    },
+    /// 이전 조회에서 존재하던 강의가 더 이상 조회되지 않음(폐강 등)
+    Removed {
+        /// 과목번호
+        code: String,
+        /// 분반
+        division: Option<String>,
+    },
+    /// 강의 요일 또는 시간이 변경됨
+    TimeChanged {
+        /// 과목번호
+        code: String,
+        /// 분반
+        division: Option<String>,
+        /// 이전 강의시간(강의실)
+        before: String,
+        /// 현재 강의시간(강의실)
+        after: String,
+    },
+    /// 강의 시간은 그대로이고 강의실만 변경됨
+    RoomChanged {
+        /// 과목번호
+        code: String,
+        /// 분반
+        division: Option<String>,
+        /// 이전 강의시간(강의실)
+        before: String,
+        /// 현재 강의시간(강의실)
+        after: String,
+    },
+}
+
+impl LectureChangeEvent {
+    /// 이전 조회 결과와 현재 조회 결과를 강의별로 비교하여 변경 사항을 반환합니다.
+    pub(crate) fn diff(
+        before: &HashMap<LectureKey, Lecture>,
+        after: &HashMap<LectureKey, Lecture>,
+    ) -> Vec<LectureChangeEvent> {
+        let mut keys: Vec<&LectureKey> = before.keys().chain(after.keys()).collect();
+        keys.sort();
+        keys.dedup();
+        keys.into_iter()
+            .flat_map(|key| match (before.get(key), after.get(key)) {
+                (Some(before), after) => Self::between(before, after),
+                (None, Some(_)) => vec![LectureChangeEvent::Added {
+                    code: key.0.clone(),
+                    division: key.1.clone(),
+                }],
+                (None, None) => Vec::with_capacity(0),
+            })
+            .collect()
+    }
+
+    /// 같은 강의의 이전 조회 결과와 현재 조회 결과를 비교하여 변경 사항을 반환합니다.
+    ///
+    /// 강의시간(강의실)은 요일별 시간과 강의실로 나누어 비교합니다. 두 문자열 모두 읽을 수 없는 형식이라면 문자열이 다를 때 시간 변경으로 취급합니다.
+    pub(crate) fn between(before: &Lecture, after: Option<&Lecture>) -> Vec<LectureChangeEvent> {
+        let code = before.code().to_string();
+        let division = before.division().map(str::to_string);
+        let Some(after) = after else {
+            return vec![LectureChangeEvent::Removed { code, division }];
+        };
+        let mut events = Vec::new();
+        if let (Some(before_seats), Some(after_seats)) =
+            (remaining_seats(before), remaining_seats(after))
+        {
+            if before_seats == 0 && after_seats > 0 {
+                events.push(LectureChangeEvent::SeatsOpened {
+                    code: code.clone(),
+                    division: division.clone(),
+                    remaining_seats: after_seats,
+                });
+            } else if before_seats > 0 && after_seats == 0 {
+                events.push(LectureChangeEvent::SeatsFilled {
+                    code: code.clone(),
+                    division: division.clone(),
+                });
+            } else if before_seats != after_seats {
+                events.push(LectureChangeEvent::SeatsChanged {
+                    code: code.clone(),
+                    division: division.clone(),
+                    before: before_seats,
+                    after: after_seats,
+                });
+            }
+        }
+        let (before_times, after_times) = (times(before), times(after));
+        let unparsed = before_times.is_empty() && after_times.is_empty();
+        let schedule_room = || {
+            (
+                before.schedule_room().to_string(),
+                after.schedule_room().to_string(),
+            )
+        };
+        if before_times != after_times
+            || (unparsed && before.schedule_room() != after.schedule_room())
+        {
+            let (before, after) = schedule_room();
+            events.push(LectureChangeEvent::TimeChanged {
+                code,
+                division,
+                before,
+                after,
+            });
+        } else if rooms(before) != rooms(after) {
+            let (before, after) = schedule_room();
+            events.push(LectureChangeEvent::RoomChanged {
+                code,
+                division,
+                before,
+                after,
+            });
+        }
+        events
+    }
+}
+
+/// 강의실을 제외한 요일별 강의 시간
+pub(super) fn times(lecture: &Lecture) -> Vec<(Weekday, TimeOfDay, TimeOfDay)> {
+    let mut times: Vec<_> = lecture
+        .schedules()
+        .iter()
+        .map(|time| (time.weekday(), time.start(), time.end()))
+        .collect();
+    times.sort();
+    times.dedup();
+    times
+}
+
+/// 요일별 강의 시간과 강의실
+pub(super) fn rooms(lecture: &Lecture) -> Vec<(Weekday, TimeOfDay, Option<String>)> {
+    let mut rooms: Vec<_> = lecture
+        .schedules()
+        .into_iter()
+        .map(|time| {
+            (
+                time.weekday(),
+                time.start(),
+                time.place().map(str::to_string),
+            )
+        })
+        .collect();
+    rooms.sort();
+    rooms
+}
+
+fn remaining_seats(lecture: &Lecture) -> Option<u32> {
+    lecture.remaining_seats().trim().parse().ok()
+}
+
+type LectureKey = (String, Option<String>);
+
+/// 특정 강의들의 여석과 강의 정보 변경을 주기적으로 조회하는 감시자
+///
+/// [`CourseScheduleApplication::watch_lectures()`]로 생성합니다.
+/// ### 예시
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// # use std::{sync::Arc, time::Duration};
+/// # use futures::StreamExt;
+/// # use rusaint::USaintSession;
+/// # use rusaint::application::USaintClientBuilder;
+/// # use rusaint::application::course_schedule::CourseScheduleApplication;
+/// # use rusaint::model::SemesterType;
+/// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+/// let mut app = USaintClientBuilder::new().session(session).build_into::<CourseScheduleApplication>().await.unwrap();
+/// let watcher = app
+///     .watch_lectures(2025, SemesterType::One)
+///     .target("21500123", Some("01"))
+///     .interval(Duration::from_secs(30));
+/// let mut stream = Box::pin(watcher.into_stream());
+/// while let Some(event) = stream.next().await {
+///     println!("{:?}", event);
+/// }
+/// # })
+/// ```
+#[derive(Debug)]
+pub struct LectureWatcher<'app> {
+    app: &'app mut CourseScheduleApplication,
+    year: u32,
+    semester: SemesterType,
+    targets: Vec<LectureKey>,
+    interval: Duration,
+    snapshot: HashMap<LectureKey, Lecture>,
+    last_polled: Option<Instant>,
+}
+
+impl<'app> LectureWatcher<'app> {
+    /// 설정할 수 있는 가장 짧은 조회 간격
+    pub const MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+    /// 기본 조회 간격
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(30);
+
+    pub(super) fn new(
+        app: &'app mut CourseScheduleApplication,
+        year: u32,
+        semester: SemesterType,
+    ) -> Self {
+        Self {
+            app,
+            year,
+            semester,
+            targets: Vec::new(),
+            interval: Self::DEFAULT_INTERVAL,
+            snapshot: HashMap::new(),
+            last_polled: None,
+        }
+    }
+
+    /// 감시할 강의를 추가합니다. 분반이 `None`이라면 해당 과목의 모든 분반을 감시합니다.
+    pub fn target(mut self, code: &str, division: Option<&str>) -> Self {
+        self.targets
+            .push((code.to_string(), division.map(str::to_string)));
+        self
+    }
+
+    /// 연속된 두 조회 사이의 최소 간격을 지정합니다. [`MIN_INTERVAL`](Self::MIN_INTERVAL)보다 짧은 간격은 [`MIN_INTERVAL`](Self::MIN_INTERVAL)로 조정됩니다.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval.max(Self::MIN_INTERVAL);
+        self
+    }
+
+    /// 현재 설정된 조회 간격을 반환합니다.
+    pub fn current_interval(&self) -> Duration {
+        self.interval
+    }
+
+    fn is_target(&self, lecture: &Lecture) -> bool {
+        self.targets.iter().any(|(code, division)| {
+            lecture.code() == code
+                && division
+                    .as_deref()
+                    .is_none_or(|division| lecture.division() == Some(division))
+        })
+    }
+
+    /// 감시 중인 강의를 다시 조회하고, 이전 조회와 비교한 변경 사항을 반환합니다.
+    ///
+    /// 이전 조회로부터 조회 간격이 지나지 않았다면 간격이 지날 때까지 기다립니다. 첫 조회는 기준 정보를 저장하므로 변경 사항을 반환하지 않습니다.
+    pub async fn poll(&mut self) -> Result<Vec<LectureChangeEvent>, RusaintError> {
+        let first = self.last_polled.is_none();
+        if let Some(last_polled) = self.last_polled {
+            sleep_until(last_polled + self.interval).await;
+        }
+        self.last_polled = Some(Instant::now());
+        let mut codes: Vec<&str> = self.targets.iter().map(|(code, _)| code.as_str()).collect();
+        codes.sort();
+        codes.dedup();
+        let mut current: HashMap<LectureKey, Lecture> = HashMap::new();
+        for code in codes
+            .into_iter()
+            .map(str::to_string)
+            .collect::<Vec<String>>()
+        {
+            let category = LectureCategory::find_by_lecture(&code);
+            let lectures = match self
+                .app
+                .find_lectures(self.year, self.semester, &category)
+                .await
+            {
+                Ok(lectures) => lectures.collect::<Vec<Lecture>>(),
+                Err(RusaintError::ApplicationError(ApplicationError::NoLectureResult)) => continue,
+                Err(err) => return Err(err),
+            };
+            for lecture in lectures
+                .into_iter()
+                .filter(|lecture| self.is_target(lecture))
+            {
+                let key = (
+                    lecture.code().to_string(),
+                    lecture.division().map(str::to_string),
+                );
+                current.insert(key, lecture);
+            }
+        }
+        let events = if first {
+            Vec::with_capacity(0)
+        } else {
+            LectureChangeEvent::diff(&self.snapshot, &current)
+        };
+        self.snapshot = current;
+        Ok(events)
+    }
+
+    /// 감시자를 변경 사항의 비동기 스트림으로 변환합니다. 스트림은 종료되지 않으며, 조회 중 발생한 오류도 스트림의 항목으로 전달됩니다.
+    pub fn into_stream(
+        self,
+    ) -> impl Stream<Item = Result<LectureChangeEvent, RusaintError>> + 'app {
+        stream::unfold(
+            (self, VecDeque::new()),
+            |(mut watcher, mut pending)| async move {
+                loop {
+                    if let Some(event) = pending.pop_front() {
+                        return Some((Ok(event), (watcher, pending)));
+                    }
+                    match watcher.poll().await {
+                        Ok(events) => pending.extend(events),
+                        Err(err) => return Some((Err(err), (watcher, pending))),
+                    }
+                }
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::LectureChangeEvent;
+    use crate::application::course_schedule::model::{self, Lecture};
+
+    fn lecture(remaining_seats: &str, schedule_room: &str) -> Lecture {
+        model::test::lecture(&[
+            ("여석", remaining_seats),
+            ("강의시간(강의실)", schedule_room),
+        ])
+    }
+
+    #[test]
+    fn lecture_change_events() {
+        let code = "21500123".to_string();
+        let division = Some("01".to_string());
+        let full = lecture("0", "월 09:00-10:15 (정보과학관 21203)");
+        let open = lecture("3", "월 09:00-10:15 (정보과학관 21203)");
+        let moved = lecture("3", "월 09:00-10:15 (정보과학관 21204)");
+        assert_eq!(
+            LectureChangeEvent::between(&full, Some(&open)),
+            vec![LectureChangeEvent::SeatsOpened {
+                code: code.clone(),
+                division: division.clone(),
+                remaining_seats: 3
+            }]
+        );
+        assert_eq!(
+            LectureChangeEvent::between(&open, Some(&full)),
+            vec![LectureChangeEvent::SeatsFilled {
+                code: code.clone(),
+                division: division.clone(),
+            }]
+        );
+        assert!(matches!(
+            LectureChangeEvent::between(&open, Some(&moved)).as_slice(),
+            [LectureChangeEvent::RoomChanged { .. }]
+        ));
+        let rescheduled = lecture("3", "화 09:00-10:15 (정보과학관 21203)");
+        assert!(matches!(
+            LectureChangeEvent::between(&open, Some(&rescheduled)).as_slice(),
+            [LectureChangeEvent::TimeChanged { .. }]
+        ));
+        let reformatted = lecture("3", "월 09:00-10:15   (정보과학관 21203)");
+        assert!(LectureChangeEvent::between(&open, Some(&reformatted)).is_empty());
+        assert_eq!(
+            LectureChangeEvent::between(&open, None),
+            vec![LectureChangeEvent::Removed { code, division }]
+        );
+        assert!(LectureChangeEvent::between(&open, Some(&open)).is_empty());
+    }
+
+    #[test]
+    fn diff_snapshots() {
+        let snapshot = |lectures: &[(&str, &str, &str)]| -> HashMap<_, _> {
+            lectures
+                .iter()
+                .map(|(division, remaining_seats, schedule_room)| {
+                    let lecture = model::test::lecture(&[
+                        ("분반", division),
+                        ("여석", remaining_seats),
+                        ("강의시간(강의실)", schedule_room),
+                    ]);
+                    let key = (
+                        lecture.code().to_string(),
+                        lecture.division().map(str::to_string),
+                    );
+                    (key, lecture)
+                })
+                .collect()
+        };
+        let before = snapshot(&[("01", "0", "월 09:00-10:15"), ("02", "0", "화 09:00-10:15")]);
+        let after = snapshot(&[("01", "2", "월 09:00-10:15"), ("03", "5", "수 09:00-10:15")]);
+        let events = LectureChangeEvent::diff(&before, &after);
+        assert_eq!(events.len(), 3);
+        assert!(matches!(events[0], LectureChangeEvent::SeatsOpened { .. }));
+        assert_eq!(
+            events[1],
+            LectureChangeEvent::Removed {
+                code: "21500123".to_string(),
+                division: Some("02".to_string()),
+            }
+        );
+        assert_eq!(
+            events[2],
+            LectureChangeEvent::Added {
+                code: "21500123".to_string(),
+                division: Some("03".to_string()),
+            }
+        );
+        assert!(LectureChangeEvent::diff(&after, &after).is_empty());
+    }
+}
@@ -6,6 +6,7 @@ use serde::{
 };
 
 use crate::application::utils::de_with::deserialize_with_trim;
+use crate::model::{Date, parse_date};
 use wdpe::element::parser::ElementParser;
 use wdpe::{
     element::{complex::sap_table::FromSapTable, definition::ElementDefinition},
@@ -113,6 +114,11 @@ impl GraduationStudent {
         &self.audit_date
     }
 
+    /// 졸업사정일자를 [`Date`]로 변환하여 반환합니다.
+    pub fn parsed_audit_date(&self) -> Option<Date> {
+        parse_date(self.audit_date())
+    }
+
     /// 졸업학점
     pub fn graduation_points(&self) -> f32 {
         self.graduation_points
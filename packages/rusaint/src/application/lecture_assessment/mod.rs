@@ -1,6 +1,14 @@
-use model::LectureAssessmentResult;
+use model::{AssessmentQuestionResult, LectureAssessmentDetail, LectureAssessmentResult};
+use scraper::Selector;
+use serde::{
+    Deserialize,
+    de::{IntoDeserializer, value::MapDeserializer},
+};
+use statistics::{AssessmentSemester, AssessmentStatistics, AssessmentTarget};
+use std::collections::HashMap;
 
 use super::{USaintApplication, USaintClient};
+use crate::application::utils::popup::close_popups;
 use crate::application::utils::sap_table::try_table_into_with_scroll;
 use crate::application::utils::semester::get_selected_semester;
 use crate::{ApplicationError, RusaintError, model::SemesterType};
@@ -17,7 +25,7 @@ use wdpe::{
     },
     define_elements,
     element::{
-        ElementDefWrapper,
+        ElementDefWrapper, ElementWrapper,
         action::Button,
         complex::{
             SapTable,
@@ -26,7 +34,7 @@ use wdpe::{
         definition::ElementDefinition,
         selection::ComboBox,
     },
-    error::{ElementError, WebDynproError},
+    error::{BodyError, ElementError, WebDynproError},
 };
 
 /// [강의평가조회](https://ecc.ssu.ac.kr/sap/bc/webdynpro/SAP/ZCMB2W1010)
@@ -58,6 +66,9 @@ impl<'a> LectureAssessmentApplication {
         TABLE: SapTable<'a> = "ZCMB2W1010.ID_0001:MAIN.TABLE";
     }
 
+    /// 결과 테이블의 각 행에 있는 상세 보기 버튼 ID의 접두사(뒤에 행 번호가 붙음)
+    const DETAIL_BUTTON_PREFIX: &'static str = "ZCMB2W1010.ID_0001:MAIN.BTN_DETAIL";
+
     fn semester_to_key(semester: SemesterType) -> &'static str {
         match semester {
             SemesterType::One => "090",
@@ -181,7 +192,144 @@ impl<'a> LectureAssessmentApplication {
         }
         Ok(try_table_into_with_scroll(&mut self.client, parser, Self::TABLE).await?)
     }
+
+    /// 주어진 강의평가 결과의 상세 화면을 열어 문항별 평균 점수와 응답자 수를 가져옵니다.
+    /// ### 예시
+    /// ```no_run
+    /// # tokio_test::block_on(async {
+    /// # use std::sync::Arc;
+    /// # use rusaint::USaintSession;
+    /// # use rusaint::application::USaintClientBuilder;
+    /// # use rusaint::application::lecture_assessment::LectureAssessmentApplication;
+    /// # use rusaint::model::SemesterType;
+    /// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+    /// let mut app = USaintClientBuilder::new().session(session).build_into::<LectureAssessmentApplication>().await.unwrap();
+    /// let results = app.find_assessments(2024, SemesterType::Two, Some("자료구조"), None, None).await.unwrap();
+    /// let detail = app.assessment_detail(&results[0]).await.unwrap();
+    /// for question in detail.questions() {
+    ///     println!("{}: {:?} ({:?}명)", question.question(), question.average(), question.responses());
+    /// }
+    /// # })
+    /// ```
+    pub async fn assessment_detail(
+        &mut self,
+        result: &LectureAssessmentResult,
+    ) -> Result<LectureAssessmentDetail, RusaintError> {
+        close_popups(&mut self.client).await?;
+        let year = result.parsed_year().ok_or_else(|| {
+            WebDynproError::from(ElementError::InvalidContent {
+                element: Self::TABLE.id().to_string(),
+                content: format!("year: {}", result.year()),
+            })
+        })?;
+        self.search(
+            &year.to_string(),
+            result.semester(),
+            Some(result.lecture_name()),
+            Some(result.lecture_code()),
+            Some(result.professor()),
+        )
+        .await?;
+        let detail_event = {
+            let parser = ElementParser::new(self.body());
+            let table = parser.read(SapTableBodyCommand::new(Self::TABLE))?;
+            table
+                .iter()
+                .find(|row| {
+                    row.try_row_into::<HashMap<String, String>>(table.header(), &parser)
+                        .ok()
+                        .and_then(|map| {
+                            let map_de: MapDeserializer<_, serde::de::value::Error> =
+                                map.into_deserializer();
+                            LectureAssessmentResult::deserialize(map_de).ok()
+                        })
+                        .is_some_and(|row_result| row_result.is_same_lecture(result))
+                })
+                .and_then(|row| {
+                    row.iter_value(&parser).find_map(|cell| {
+                        let Ok(SapTableCellWrapper::Normal(cell)) = cell else {
+                            return None;
+                        };
+                        match cell.content()? {
+                            ElementDefWrapper::Button(btn)
+                                if btn.id().starts_with(Self::DETAIL_BUTTON_PREFIX) =>
+                            {
+                                parser.element_from_def(&btn).ok()?.press().ok()
+                            }
+                            _ => None,
+                        }
+                    })
+                })
+        };
+        let Some(detail_event) = detail_event else {
+            return Err(ApplicationError::NoLectureAssessmentDetail.into());
+        };
+        self.client.process_event(false, detail_event).await?;
+        let parse_table_in_popup =
+            |body: &Body| -> Result<Vec<AssessmentQuestionResult>, WebDynproError> {
+                let table_inside_popup_selector =
+                    Selector::parse(r#"[ct="PW"] [ct="ST"]"#).unwrap();
+                let parser = ElementParser::new(body);
+                let mut table_inside_popup = parser.document().select(&table_inside_popup_selector);
+                let table_ref = table_inside_popup
+                    .next()
+                    .ok_or(BodyError::NoSuchElement("Table in popup".to_string()))?;
+                let table_elem: SapTable<'_> = ElementWrapper::from_ref(table_ref)?.try_into()?;
+                table_elem
+                    .table()?
+                    .try_table_into::<AssessmentQuestionResult>(&parser)
+            };
+        let questions = parse_table_in_popup(self.body())?;
+        close_popups(&mut self.client).await?;
+        if questions.is_empty() {
+            return Err(ApplicationError::NoLectureAssessmentDetail.into());
+        }
+        Ok(LectureAssessmentDetail::new(result.clone(), questions))
+    }
+
+    /// 주어진 범위의 학년도(`from_year`부터 `to_year`까지)의 모든 학기에서 과목코드 또는 교수명으로 강의평가를 검색하여 통계를 계산합니다.
+    ///
+    /// 강의평가가 없는 학기는 결과가 없는 것으로 취급하며, 각 결과의 학년도와 학기는 검색한 학년도와 학기를 기준으로 합니다.
+    pub async fn assessment_statistics(
+        &mut self,
+        target: &AssessmentTarget,
+        from_year: u32,
+        to_year: u32,
+    ) -> Result<AssessmentStatistics, RusaintError> {
+        let (lecture_code, professor_name) = match target {
+            AssessmentTarget::Lecture { code } => (Some(*code), None),
+            AssessmentTarget::Professor { name } => (None, Some(name.as_str())),
+        };
+        let mut semesters = Vec::new();
+        for year in from_year..=to_year {
+            for semester in [
+                SemesterType::One,
+                SemesterType::Summer,
+                SemesterType::Two,
+                SemesterType::Winter,
+            ] {
+                let results = match self
+                    .find_assessments(year, semester, None, lecture_code, professor_name)
+                    .await
+                {
+                    Ok(results) => results
+                        .into_iter()
+                        .filter(|result| result.parsed_year().is_none_or(|parsed| parsed == year))
+                        .collect(),
+                    Err(RusaintError::ApplicationError(ApplicationError::NoLectureAssessments)) => {
+                        continue;
+                    }
+                    Err(err) => return Err(err),
+                };
+                semesters.push(AssessmentSemester::new(year, semester, results));
+            }
+        }
+        Ok(AssessmentStatistics::new(target.clone(), semesters))
+    }
 }
 
 /// [`LectureAssessmentApplication`] 애플리케이션에 사용되는 데이터
 pub mod model;
+
+/// 여러 학기에 걸친 강의평가 통계
+pub mod statistics;
@@ -6,7 +6,8 @@ use serde::{
 };
 
 use crate::application::utils::de_with::{
-    deserialize_f32_string, deserialize_semester_type, deserialize_u32_string,
+    deserialize_f32_string, deserialize_optional_f32_string, deserialize_optional_u32_string,
+    deserialize_semester_type, deserialize_u32_string, deserialize_with_trim,
 };
 use crate::model::SemesterType;
 use wdpe::element::parser::ElementParser;
@@ -59,6 +60,11 @@ impl LectureAssessmentResult {
         &self.year
     }
 
+    /// 강의 학년도를 숫자로 반환합니다. 변환할 수 없다면 `None`을 반환합니다.
+    pub fn parsed_year(&self) -> Option<u32> {
+        self.year.trim().parse().ok()
+    }
+
     /// 강의 학기를 반환합니다.
     pub fn semester(&self) -> SemesterType {
         self.semester
@@ -121,3 +127,172 @@ impl<'body> FromSapTable<'body> for LectureAssessmentResult {
         )
     }
 }
+
+impl LectureAssessmentResult {
+    /// 주어진 강의평가 결과와 같은 강의(학년도, 학기, 과목코드, 교수명)의 결과인지 확인합니다.
+    pub(crate) fn is_same_lecture(&self, other: &LectureAssessmentResult) -> bool {
+        self.parsed_year() == other.parsed_year()
+            && self.semester == other.semester
+            && self.lecture_code == other.lecture_code
+            && self.professor.trim() == other.professor.trim()
+    }
+}
+
+/// 강의평가의 문항별 결과
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct AssessmentQuestionResult {
+    #[serde(
+        rename(deserialize = "번호"),
+        deserialize_with = "deserialize_optional_u32_string"
+    )]
+    number: Option<u32>,
+    #[serde(
+        rename(deserialize = "평가영역"),
+        deserialize_with = "deserialize_with_trim"
+    )]
+    category: String,
+    #[serde(
+        rename(deserialize = "평가문항"),
+        deserialize_with = "deserialize_with_trim"
+    )]
+    question: String,
+    #[serde(
+        rename(deserialize = "평균"),
+        deserialize_with = "deserialize_optional_f32_string"
+    )]
+    average: Option<f32>,
+    #[serde(
+        rename(deserialize = "응답자수"),
+        deserialize_with = "deserialize_optional_u32_string"
+    )]
+    responses: Option<u32>,
+}
+
+impl AssessmentQuestionResult {
+    /// 문항 번호를 반환합니다.
+    pub fn number(&self) -> Option<u32> {
+        self.number
+    }
+
+    /// 문항의 평가 영역을 반환합니다. 영역이 표시되지 않는다면 빈 문자열을 반환합니다.
+    pub fn category(&self) -> &str {
+        &self.category
+    }
+
+    /// 평가 문항을 반환합니다.
+    pub fn question(&self) -> &str {
+        &self.question
+    }
+
+    /// 문항의 평균 점수를 반환합니다.
+    pub fn average(&self) -> Option<f32> {
+        self.average
+    }
+
+    /// 문항의 응답자 수를 반환합니다.
+    pub fn responses(&self) -> Option<u32> {
+        self.responses
+    }
+}
+
+impl<'body> FromSapTable<'body> for AssessmentQuestionResult {
+    fn from_table(
+        header: &'body wdpe::element::complex::sap_table::SapTableHeader,
+        row: &'body wdpe::element::complex::sap_table::SapTableRow,
+        parser: &'body ElementParser,
+    ) -> Result<Self, WebDynproError> {
+        let map_string = row.try_row_into::<HashMap<String, String>>(header, parser)?;
+        let map_de: MapDeserializer<_, serde::de::value::Error> = map_string.into_deserializer();
+        Ok(
+            Self::deserialize(map_de).map_err(|e| ElementError::InvalidContent {
+                element: row.table_def().id().to_string(),
+                content: e.to_string(),
+            })?,
+        )
+    }
+}
+
+/// 강의평가의 문항별 상세 결과
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct LectureAssessmentDetail {
+    result: LectureAssessmentResult,
+    questions: Vec<AssessmentQuestionResult>,
+}
+
+impl LectureAssessmentDetail {
+    pub(crate) fn new(
+        result: LectureAssessmentResult,
+        questions: Vec<AssessmentQuestionResult>,
+    ) -> Self {
+        Self { result, questions }
+    }
+
+    /// 강의평가 결과를 반환합니다.
+    pub fn result(&self) -> &LectureAssessmentResult {
+        &self.result
+    }
+
+    /// 문항별 결과를 유세인트에 표시되는 순서대로 반환합니다.
+    pub fn questions(&self) -> &[AssessmentQuestionResult] {
+        &self.questions
+    }
+
+    /// 응답자 수로 가중한 전체 문항의 평균 점수를 반환합니다. 응답자 수를 알 수 없는 문항은 가중치 1로 계산합니다.
+    pub fn weighted_average(&self) -> Option<f32> {
+        let (sum, weight) = self
+            .questions
+            .iter()
+            .filter_map(|question| {
+                let weight = question.responses.unwrap_or(1).max(1) as f32;
+                Some((question.average? * weight, weight))
+            })
+            .fold((0.0, 0.0), |(sum, total), (value, weight)| {
+                (sum + value, total + weight)
+            });
+        (weight > 0.0).then(|| sum / weight)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AssessmentQuestionResult, LectureAssessmentDetail, LectureAssessmentResult};
+    use crate::application::utils::from_fields;
+
+    #[test]
+    fn question_results() {
+        let result: LectureAssessmentResult = from_fields(&[
+            ("년도", "2024"),
+            ("학기", "2 학기"),
+            ("과목코드", "21500123"),
+            ("과목명", "자료구조"),
+            ("학점", "3.0"),
+            ("교수명", "홍길동"),
+            ("소속대학", "IT대학"),
+            ("소속학과", "컴퓨터학부"),
+            ("직위명", "교수"),
+            ("점수", "92.5"),
+        ]);
+        let first: AssessmentQuestionResult = from_fields(&[
+            ("번호", "1"),
+            ("평가영역", "강의준비"),
+            ("평가문항", " 강의 준비가 충실하였다. "),
+            ("평균", "4.5"),
+            ("응답자수", "30"),
+        ]);
+        let second: AssessmentQuestionResult = from_fields(&[
+            ("번호", "2"),
+            ("평가영역", "강의내용"),
+            ("평가문항", "강의 내용이 유익하였다."),
+            ("평균", "4.0"),
+            ("응답자수", "10"),
+        ]);
+        assert_eq!(first.number(), Some(1));
+        assert_eq!(first.category(), "강의준비");
+        assert_eq!(first.question(), "강의 준비가 충실하였다.");
+        assert_eq!(second.responses(), Some(10));
+        let detail = LectureAssessmentDetail::new(result, vec![first, second]);
+        assert_eq!(detail.weighted_average(), Some(4.375));
+    }
+}
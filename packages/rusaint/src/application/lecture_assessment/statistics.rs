@@ -0,0 +1,322 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{application::lecture_assessment::model::LectureAssessmentResult, model::SemesterType};
+
+/// 강의평가 점수 추세를 상승 또는 하락으로 판단하는 정규 학기당 점수 변화량
+///
+/// [`AssessmentStatistics::trend_slope()`]의 절댓값이 이 값 이상이면 [`AssessmentTrend::Rising`] 또는 [`AssessmentTrend::Falling`]으로,
+/// 그 외에는 [`AssessmentTrend::Steady`]로 판단합니다. 강의평가 점수는 100점 만점이므로 정규 학기마다 1점 이상 변하는 경우를 추세로 봅니다.
+pub const TREND_THRESHOLD: f32 = 1.0;
+
+/// 강의평가 통계를 집계할 대상
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum AssessmentTarget {
+    /// 과목코드가 일치하는 강의
+    Lecture {
+        /// 과목코드
+        code: u32,
+    },
+    /// 교수명이 일치하는 교수(강사)의 강의
+    Professor {
+        /// 교수명
+        name: String,
+    },
+}
+
+impl AssessmentTarget {
+    /// 강의평가 결과가 집계 대상에 해당하는지 확인합니다.
+    pub fn matches(&self, result: &LectureAssessmentResult) -> bool {
+        match self {
+            AssessmentTarget::Lecture { code } => result.lecture_code() == *code,
+            AssessmentTarget::Professor { name } => result.professor().trim() == name.trim(),
+        }
+    }
+}
+
+/// 강의평가 점수 추세
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum AssessmentTrend {
+    /// 상승
+    Rising,
+    /// 유지
+    Steady,
+    /// 하락
+    Falling,
+    /// 강의평가가 있는 학기가 부족하여 판단할 수 없음
+    Unknown,
+}
+
+/// 한 학기의 강의평가 결과
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct AssessmentSemester {
+    year: u32,
+    semester: SemesterType,
+    results: Vec<LectureAssessmentResult>,
+}
+
+impl AssessmentSemester {
+    /// 검색한 학년도, 학기와 그 학기의 강의평가 결과로 새로운 [`AssessmentSemester`]를 만듭니다.
+    pub fn new(year: u32, semester: SemesterType, results: Vec<LectureAssessmentResult>) -> Self {
+        Self {
+            year,
+            semester,
+            results,
+        }
+    }
+
+    /// 학년도를 반환합니다.
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// 학기를 반환합니다.
+    pub fn semester(&self) -> SemesterType {
+        self.semester
+    }
+
+    /// 해당 학기의 강의평가 결과(분반, 교수별)를 반환합니다.
+    pub fn results(&self) -> &[LectureAssessmentResult] {
+        &self.results
+    }
+
+    /// 해당 학기 강의평가 점수의 평균을 반환합니다. 결과가 없다면 `None`을 반환합니다.
+    pub fn score(&self) -> Option<f32> {
+        mean(self.results.iter().map(LectureAssessmentResult::score))
+    }
+
+    /// 정규 학기의 순서(학년도 * 2 + 학기)를 반환합니다. 계절학기라면 `None`을 반환합니다.
+    fn regular_ordinal(&self) -> Option<f32> {
+        let index = match self.semester {
+            SemesterType::One => 0,
+            SemesterType::Two => 1,
+            SemesterType::Summer | SemesterType::Winter => return None,
+        };
+        Some((self.year * 2 + index) as f32)
+    }
+}
+
+/// 여러 학기에 걸친 강의평가 통계
+///
+/// 평균, 최저, 최고 점수는 모든 학기의 개별 강의평가 결과로 계산하며, 추세는 정규 학기(1학기, 2학기)별 평균 점수에 대한 최소제곱법 기울기로 판단합니다.
+/// 계절학기는 수강생 구성이 달라 추세 계산에서 제외하며, 강의평가가 없는 정규 학기도 간격으로 반영합니다.
+/// ### 예시
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// # use std::sync::Arc;
+/// # use rusaint::USaintSession;
+/// # use rusaint::application::USaintClientBuilder;
+/// # use rusaint::application::lecture_assessment::{LectureAssessmentApplication, statistics::AssessmentTarget};
+/// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+/// let mut app = USaintClientBuilder::new().session(session).build_into::<LectureAssessmentApplication>().await.unwrap();
+/// let target = AssessmentTarget::Professor { name: "홍길동".to_string() };
+/// let statistics = app.assessment_statistics(&target, 2021, 2024).await.unwrap();
+/// println!("{:?} {:?} {:?}", statistics.mean(), statistics.max(), statistics.trend());
+/// # })
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct AssessmentStatistics {
+    target: AssessmentTarget,
+    semesters: Vec<AssessmentSemester>,
+    mean: Option<f32>,
+    min: Option<f32>,
+    max: Option<f32>,
+    trend: AssessmentTrend,
+    trend_slope: Option<f32>,
+}
+
+impl AssessmentStatistics {
+    /// 학기별 강의평가 결과로 통계를 계산합니다. 집계 대상에 해당하지 않는 결과와 결과가 없는 학기는 제외됩니다.
+    pub fn new(target: AssessmentTarget, semesters: Vec<AssessmentSemester>) -> Self {
+        let mut semesters: Vec<AssessmentSemester> = semesters
+            .into_iter()
+            .map(|semester| AssessmentSemester {
+                results: semester
+                    .results
+                    .into_iter()
+                    .filter(|result| target.matches(result))
+                    .collect(),
+                ..semester
+            })
+            .filter(|semester| !semester.results.is_empty())
+            .collect();
+        semesters.sort_by_key(|semester| (semester.year, semester.semester));
+        let scores = || {
+            semesters
+                .iter()
+                .flat_map(|semester| semester.results.iter().map(LectureAssessmentResult::score))
+        };
+        let trend_slope = slope(
+            &semesters
+                .iter()
+                .filter_map(|semester| Some((semester.regular_ordinal()?, semester.score()?)))
+                .collect::<Vec<(f32, f32)>>(),
+        );
+        let trend = match trend_slope {
+            Some(slope) if slope >= TREND_THRESHOLD => AssessmentTrend::Rising,
+            Some(slope) if slope <= -TREND_THRESHOLD => AssessmentTrend::Falling,
+            Some(_) => AssessmentTrend::Steady,
+            None => AssessmentTrend::Unknown,
+        };
+        Self {
+            mean: mean(scores()),
+            min: scores().reduce(f32::min),
+            max: scores().reduce(f32::max),
+            target,
+            semesters,
+            trend,
+            trend_slope,
+        }
+    }
+
+    /// 집계 대상을 반환합니다.
+    pub fn target(&self) -> &AssessmentTarget {
+        &self.target
+    }
+
+    /// 강의평가 결과가 있는 학기를 학기 순으로 반환합니다.
+    pub fn semesters(&self) -> &[AssessmentSemester] {
+        &self.semesters
+    }
+
+    /// 전체 강의평가 점수의 평균을 반환합니다.
+    pub fn mean(&self) -> Option<f32> {
+        self.mean
+    }
+
+    /// 가장 낮은 강의평가 점수를 반환합니다.
+    pub fn min(&self) -> Option<f32> {
+        self.min
+    }
+
+    /// 가장 높은 강의평가 점수를 반환합니다.
+    pub fn max(&self) -> Option<f32> {
+        self.max
+    }
+
+    /// 강의평가 점수 추세를 반환합니다.
+    pub fn trend(&self) -> AssessmentTrend {
+        self.trend
+    }
+
+    /// 정규 학기당 강의평가 점수 변화량을 반환합니다. 강의평가가 있는 정규 학기가 2개 미만이라면 `None`을 반환합니다.
+    pub fn trend_slope(&self) -> Option<f32> {
+        self.trend_slope
+    }
+}
+
+fn mean(values: impl Iterator<Item = f32>) -> Option<f32> {
+    let (sum, count) = values.fold((0.0, 0), |(sum, count), value| (sum + value, count + 1));
+    (count > 0).then(|| sum / count as f32)
+}
+
+/// `(학기 순서, 점수)` 목록에 대해 최소제곱법으로 계산한 학기당 점수 변화량
+fn slope(points: &[(f32, f32)]) -> Option<f32> {
+    if points.len() < 2 {
+        return None;
+    }
+    let n = points.len() as f32;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f32>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f32>() / n;
+    let (numerator, denominator) =
+        points
+            .iter()
+            .fold((0.0, 0.0), |(numerator, denominator), (x, y)| {
+                let dx = x - mean_x;
+                (numerator + dx * (y - mean_y), denominator + dx * dx)
+            });
+    (denominator > 0.0).then(|| numerator / denominator)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{AssessmentSemester, AssessmentStatistics, AssessmentTarget, AssessmentTrend};
+    use crate::{
+        application::{lecture_assessment::model::LectureAssessmentResult, utils::from_fields},
+        model::SemesterType,
+    };
+
+    fn result(year: &str, code: &str, professor: &str, score: &str) -> LectureAssessmentResult {
+        from_fields(&[
+            ("년도", year),
+            ("학기", "1 학기"),
+            ("과목코드", code),
+            ("과목명", "자료구조"),
+            ("학점", "3.0"),
+            ("교수명", professor),
+            ("소속대학", "IT대학"),
+            ("소속학과", "컴퓨터학부"),
+            ("직위명", "교수"),
+            ("점수", score),
+        ])
+    }
+
+    #[test]
+    fn aggregate_statistics() {
+        let semesters = vec![
+            AssessmentSemester::new(
+                2023,
+                SemesterType::One,
+                vec![
+                    result("2023", "21500123", "홍길동", "88.0"),
+                    result("2023", "21500999", "홍길동", "70.0"),
+                ],
+            ),
+            AssessmentSemester::new(2024, SemesterType::One, vec![]),
+            AssessmentSemester::new(
+                2022,
+                SemesterType::One,
+                vec![
+                    result("2022", "21500123", "홍길동", "80.0"),
+                    result("2022", "21500123", "김철수", "84.0"),
+                ],
+            ),
+        ];
+        let statistics =
+            AssessmentStatistics::new(AssessmentTarget::Lecture { code: 21500123 }, semesters);
+        assert_eq!(statistics.semesters().len(), 2);
+        assert_eq!(statistics.semesters()[0].year(), 2022);
+        assert_eq!(statistics.semesters()[0].score(), Some(82.0));
+        assert_eq!(statistics.mean(), Some(84.0));
+        assert_eq!(statistics.min(), Some(80.0));
+        assert_eq!(statistics.max(), Some(88.0));
+        assert_eq!(statistics.trend_slope(), Some(3.0));
+        assert_eq!(statistics.trend(), AssessmentTrend::Rising);
+
+        let with_seasonal = AssessmentStatistics::new(
+            AssessmentTarget::Lecture { code: 21500123 },
+            vec![
+                AssessmentSemester::new(
+                    2023,
+                    SemesterType::One,
+                    vec![result("2023", "21500123", "홍길동", "80.0")],
+                ),
+                AssessmentSemester::new(
+                    2023,
+                    SemesterType::Summer,
+                    vec![result("2023", "21500123", "홍길동", "60.0")],
+                ),
+                AssessmentSemester::new(
+                    2023,
+                    SemesterType::Two,
+                    vec![result("2023", "21500123", "홍길동", "80.5")],
+                ),
+            ],
+        );
+        assert_eq!(with_seasonal.semesters().len(), 3);
+        assert_eq!(with_seasonal.trend_slope(), Some(0.5));
+        assert_eq!(with_seasonal.trend(), AssessmentTrend::Steady);
+
+        let empty = AssessmentStatistics::new(
+            AssessmentTarget::Professor {
+                name: "이영희".to_string(),
+            },
+            vec![],
+        );
+        assert_eq!(empty.mean(), None);
+        assert_eq!(empty.trend(), AssessmentTrend::Unknown);
+    }
+}
@@ -0,0 +1,470 @@
+use std::{
+    fmt::Write as _,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    application::{
+        chapel::model::GeneralChapelInformation,
+        personal_course_schedule::model::{PersonalCourseSchedule, Weekday},
+    },
+    model::{Date, TimeOfDay},
+};
+
+/// 한국 표준시의 UTC 오프셋(시)
+const KST_OFFSET_HOURS: i64 = 9;
+
+/// 매주 반복되는 하나의 수업 일정
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct CalendarEvent {
+    /// 일정 제목
+    summary: String,
+    /// 요일
+    weekday: Weekday,
+    /// 시작 시각
+    start: TimeOfDay,
+    /// 종료 시각
+    end: TimeOfDay,
+    /// 장소
+    location: String,
+    /// 교수자
+    professor: String,
+}
+
+impl CalendarEvent {
+    /// 일정 제목
+    pub fn summary(&self) -> &str {
+        &self.summary
+    }
+
+    /// 요일
+    pub fn weekday(&self) -> Weekday {
+        self.weekday
+    }
+
+    /// 시작 시각
+    pub fn start(&self) -> TimeOfDay {
+        self.start
+    }
+
+    /// 종료 시각
+    pub fn end(&self) -> TimeOfDay {
+        self.end
+    }
+
+    /// 장소
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    /// 교수자
+    pub fn professor(&self) -> &str {
+        &self.professor
+    }
+
+    /// 일정 제목, 요일, 시작 시각, 장소로 계산한 FNV-1a 해시입니다.
+    /// 일정의 순서가 바뀌어도 같은 일정은 같은 UID를 갖도록 UID에 사용합니다.
+    fn content_hash(&self) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+        const PRIME: u64 = 0x100000001b3;
+        let content = format!(
+            "{}\u{1f}{}\u{1f}{}\u{1f}{}",
+            self.summary,
+            weekday_code(self.weekday),
+            format_time(self.start),
+            self.location
+        );
+        content.bytes().fold(OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+        })
+    }
+}
+
+/// 학기 동안 매주 반복되는 시간표 일정을 iCalendar(RFC 5545) 형식으로 내보냅니다.
+///
+/// 모든 일정은 학기 시작일 이후 첫 수업일부터 학기 종료일까지 매주 반복되며, 한국 표준시(`Asia/Seoul`)를 기준으로 합니다.
+/// ### 예시
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// # use std::sync::Arc;
+/// # use rusaint::USaintSession;
+/// # use rusaint::application::USaintClientBuilder;
+/// # use rusaint::application::personal_course_schedule::{PersonalCourseScheduleApplication, calendar::ScheduleCalendar};
+/// # use rusaint::model::{Date, SemesterType};
+/// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+/// let mut app = USaintClientBuilder::new().session(session).build_into::<PersonalCourseScheduleApplication>().await.unwrap();
+/// let schedule = app.schedule(2025, SemesterType::One).await.unwrap();
+/// let calendar = ScheduleCalendar::new(
+///     "2025.03.04".parse::<Date>().unwrap(),
+///     "2025.06.20".parse::<Date>().unwrap(),
+/// )
+/// .with_schedule(&schedule);
+/// std::fs::write("schedule.ics", calendar.to_ical()).unwrap();
+/// # })
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScheduleCalendar {
+    start_date: Date,
+    end_date: Date,
+    events: Vec<CalendarEvent>,
+}
+
+impl ScheduleCalendar {
+    /// 학기 시작일과 종료일로 일정이 없는 새로운 [`ScheduleCalendar`]를 만듭니다.
+    pub fn new(start_date: Date, end_date: Date) -> Self {
+        Self {
+            start_date,
+            end_date,
+            events: Vec::new(),
+        }
+    }
+
+    /// 개인 수업 시간표의 수업을 일정에 추가합니다. 수업 시간을 알 수 없는 수업은 제외합니다.
+    pub fn with_schedule(mut self, schedule: &PersonalCourseSchedule) -> Self {
+        for event in schedule.events() {
+            let information = event.information();
+            let period = information.period();
+            let Some((start, end)) = information
+                .parsed_time()
+                .or_else(|| Some((period.start()?, period.end()?)))
+            else {
+                continue;
+            };
+            self.events.push(CalendarEvent {
+                summary: information.name().to_string(),
+                weekday: event.weekday(),
+                start,
+                end,
+                location: information.classroom().to_string(),
+                professor: information.professor().to_string(),
+            });
+        }
+        self
+    }
+
+    /// 채플 시간을 일정에 추가합니다. 채플 시간을 해석할 수 없다면 추가하지 않습니다.
+    pub fn with_chapel(mut self, chapel: &GeneralChapelInformation) -> Self {
+        let location = [chapel.chapel_room(), chapel.seat_number()]
+            .into_iter()
+            .filter(|str| !str.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        for (weekday, start, end) in parse_weekly_times(chapel.chapel_time()) {
+            self.events.push(CalendarEvent {
+                summary: "채플".to_string(),
+                weekday,
+                start,
+                end,
+                location: location.clone(),
+                professor: String::new(),
+            });
+        }
+        self
+    }
+
+    /// 학기 시작일
+    pub fn start_date(&self) -> Date {
+        self.start_date
+    }
+
+    /// 학기 종료일
+    pub fn end_date(&self) -> Date {
+        self.end_date
+    }
+
+    /// 추가된 일정 목록
+    pub fn events(&self) -> &[CalendarEvent] {
+        &self.events
+    }
+
+    /// iCalendar(RFC 5545) 형식의 문자열로 변환합니다.
+    pub fn to_ical(&self) -> String {
+        let stamp = format_utc_now();
+        let until = format_until(self.end_date);
+        let mut lines = vec![
+            "BEGIN:VCALENDAR".to_string(),
+            "VERSION:2.0".to_string(),
+            "PRODID:-//rusaint//rusaint//KO".to_string(),
+            "CALSCALE:GREGORIAN".to_string(),
+            "METHOD:PUBLISH".to_string(),
+            "BEGIN:VTIMEZONE".to_string(),
+            "TZID:Asia/Seoul".to_string(),
+            "BEGIN:STANDARD".to_string(),
+            "DTSTART:19700101T000000".to_string(),
+            "TZOFFSETFROM:+0900".to_string(),
+            "TZOFFSETTO:+0900".to_string(),
+            "TZNAME:KST".to_string(),
+            "END:STANDARD".to_string(),
+            "END:VTIMEZONE".to_string(),
+        ];
+        for event in &self.events {
+            let Some(first) = first_weekday_on_or_after(self.start_date, event.weekday) else {
+                continue;
+            };
+            if first > self.end_date {
+                continue;
+            }
+            let first = format_date(first);
+            let mut description = String::new();
+            if !event.professor.is_empty() {
+                let _ = writeln!(description, "교수: {}", event.professor);
+            }
+            if !event.location.is_empty() {
+                let _ = writeln!(description, "강의실: {}", event.location);
+            }
+            lines.push("BEGIN:VEVENT".to_string());
+            lines.push(format!(
+                "UID:{}-{}-{}-{:016x}@rusaint",
+                format_date(self.start_date),
+                weekday_code(event.weekday),
+                format_time(event.start),
+                event.content_hash()
+            ));
+            lines.push(format!("DTSTAMP:{stamp}"));
+            lines.push(format!(
+                "DTSTART;TZID=Asia/Seoul:{first}T{}",
+                format_time(event.start)
+            ));
+            lines.push(format!(
+                "DTEND;TZID=Asia/Seoul:{first}T{}",
+                format_time(event.end)
+            ));
+            lines.push(format!(
+                "RRULE:FREQ=WEEKLY;BYDAY={};UNTIL={until}",
+                weekday_code(event.weekday)
+            ));
+            lines.push(format!("SUMMARY:{}", escape_text(&event.summary)));
+            if !event.location.is_empty() {
+                lines.push(format!("LOCATION:{}", escape_text(&event.location)));
+            }
+            if !description.is_empty() {
+                lines.push(format!(
+                    "DESCRIPTION:{}",
+                    escape_text(description.trim_end())
+                ));
+            }
+            lines.push("END:VEVENT".to_string());
+        }
+        lines.push("END:VCALENDAR".to_string());
+        lines.iter().map(|line| fold_line(line)).collect()
+    }
+}
+
+/// `화 10:30-11:20`, `월3 09:00-09:50`과 같은 요일별 시간 문자열을 해석합니다.
+fn parse_weekly_times(str: &str) -> Vec<(Weekday, TimeOfDay, TimeOfDay)> {
+    let mut weekday = None;
+    let mut times = Vec::new();
+    for token in str.split(|c: char| c.is_whitespace() || c == ',' || c == '/') {
+        let (prefix, rest) = token
+            .char_indices()
+            .find(|(_, c)| c.is_ascii_digit())
+            .map_or((token, ""), |(idx, _)| token.split_at(idx));
+        if let Some(day) = prefix.chars().next().and_then(|c| {
+            let mut buf = [0; 4];
+            Weekday::from_korean(c.encode_utf8(&mut buf))
+        }) {
+            weekday = Some(day);
+        }
+        let Some((start, end)) = rest.split_once(['-', '~']) else {
+            continue;
+        };
+        let (Some(day), Ok(start), Ok(end)) = (weekday, start.parse(), end.parse()) else {
+            continue;
+        };
+        times.push((day, start, end));
+    }
+    times
+}
+
+fn first_weekday_on_or_after(date: Date, weekday: Weekday) -> Option<Date> {
+    let days = date.days_since_epoch();
+    // 1970-01-01은 목요일입니다.
+    let current = (days + 3).rem_euclid(7);
+    let offset = (weekday as i64 - current).rem_euclid(7);
+    Date::from_days_since_epoch(days + offset)
+}
+
+fn weekday_code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+fn format_date(date: Date) -> String {
+    format!("{:04}{:02}{:02}", date.year(), date.month(), date.day())
+}
+
+fn format_time(time: TimeOfDay) -> String {
+    format!("{:02}{:02}00", time.hour(), time.minute())
+}
+
+/// 종료일의 마지막 시각(한국 표준시 23:59:59)을 UTC로 변환합니다.
+fn format_until(date: Date) -> String {
+    format!("{}T{:02}5959Z", format_date(date), 23 - KST_OFFSET_HOURS)
+}
+
+fn format_utc_now() -> String {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() as i64);
+    let date = Date::from_days_since_epoch(seconds.div_euclid(86400))
+        .map_or_else(|| "19700101".to_string(), format_date);
+    let seconds = seconds.rem_euclid(86400);
+    format!(
+        "{date}T{:02}{:02}{:02}Z",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+fn escape_text(str: &str) -> String {
+    let mut escaped = String::with_capacity(str.len());
+    for char in str.chars() {
+        match char {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(char),
+        }
+    }
+    escaped
+}
+
+/// 한 줄이 75옥텟을 넘지 않도록 접고 CRLF로 끝냅니다.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + 2);
+    let mut length = 0;
+    for char in line.chars() {
+        if length + char.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            length = 1;
+        }
+        folded.push(char);
+        length += char.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ScheduleCalendar, first_weekday_on_or_after, fold_line, parse_weekly_times};
+    use crate::{
+        application::{
+            chapel::model::GeneralChapelInformation,
+            personal_course_schedule::model::{PersonalCourseSchedule, Weekday},
+            utils::from_fields,
+        },
+        model::{Date, TimeOfDay},
+    };
+
+    fn uids(ical: &str) -> Vec<&str> {
+        let mut uids: Vec<&str> = ical
+            .split("\r\n")
+            .filter(|line| line.starts_with("UID:"))
+            .collect();
+        uids.sort_unstable();
+        uids
+    }
+
+    #[test]
+    fn export_weekly_events() {
+        let rows = vec![
+            vec![Some("".to_string()), Some("화".to_string())],
+            vec![
+                Some("1교시".to_string()),
+                Some("자료구조\n홍길동\n09:00-10:15\n정보과학관 21203".to_string()),
+            ],
+        ];
+        let schedule = PersonalCourseSchedule::from_rows(rows, "TABLE").unwrap();
+        let start = "2025.03.04".parse::<Date>().unwrap();
+        let end = "2025.06.20".parse::<Date>().unwrap();
+        let ical = ScheduleCalendar::new(start, end)
+            .with_schedule(&schedule)
+            .to_ical();
+        assert!(ical.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ical.contains("DTSTART;TZID=Asia/Seoul:20250304T090000\r\n"));
+        assert!(ical.contains("DTEND;TZID=Asia/Seoul:20250304T101500\r\n"));
+        assert!(ical.contains("RRULE:FREQ=WEEKLY;BYDAY=TU;UNTIL=20250620T145959Z\r\n"));
+        assert!(ical.contains("LOCATION:정보과학관 21203\r\n"));
+        assert!(ical.ends_with("END:VCALENDAR\r\n"));
+    }
+
+    #[test]
+    fn export_chapel_with_stable_uid() {
+        let chapel: GeneralChapelInformation = from_fields(&[
+            ("분반", "12"),
+            ("시간표", "화 16:30-17:20 (한경직기념관 08110-김회권)"),
+            ("강의실", "한경직기념관"),
+            ("층수", "1"),
+            ("좌석번호", "E-12"),
+            ("결석일수", "0"),
+            ("성적", ""),
+            ("비고", ""),
+        ]);
+        assert_eq!(
+            parse_weekly_times(chapel.chapel_time()),
+            vec![(
+                Weekday::Tue,
+                TimeOfDay::new(16, 30).unwrap(),
+                TimeOfDay::new(17, 20).unwrap()
+            )]
+        );
+        let rows = vec![
+            vec![Some("".to_string()), Some("월".to_string())],
+            vec![
+                Some("1교시".to_string()),
+                Some("자료구조\n홍길동\n09:00-10:15\n정보과학관 21203".to_string()),
+            ],
+        ];
+        let schedule = PersonalCourseSchedule::from_rows(rows, "TABLE").unwrap();
+        let start = "2025.03.04".parse::<Date>().unwrap();
+        let end = "2025.06.20".parse::<Date>().unwrap();
+        let chapel_first = ScheduleCalendar::new(start, end)
+            .with_chapel(&chapel)
+            .with_schedule(&schedule);
+        assert_eq!(chapel_first.events()[0].location(), "한경직기념관 E-12");
+        let schedule_first = ScheduleCalendar::new(start, end)
+            .with_schedule(&schedule)
+            .with_chapel(&chapel);
+        let chapel_first = chapel_first.to_ical();
+        let schedule_first = schedule_first.to_ical();
+        assert_eq!(uids(&chapel_first).len(), 2);
+        assert_eq!(uids(&chapel_first), uids(&schedule_first));
+    }
+
+    #[test]
+    fn helpers() {
+        assert_eq!(
+            parse_weekly_times("화 10:30-11:20"),
+            vec![(
+                Weekday::Tue,
+                TimeOfDay::new(10, 30).unwrap(),
+                TimeOfDay::new(11, 20).unwrap()
+            )]
+        );
+        let tuesday = Date::new(2025, 3, 4).unwrap();
+        assert_eq!(
+            first_weekday_on_or_after(tuesday, Weekday::Tue),
+            Some(tuesday)
+        );
+        assert_eq!(
+            first_weekday_on_or_after(tuesday, Weekday::Mon),
+            Date::new(2025, 3, 10)
+        );
+        let folded = fold_line(&"가".repeat(30));
+        assert!(folded.split("\r\n").all(|line| line.len() <= 75));
+    }
+}
@@ -0,0 +1,304 @@
+use std::{collections::BTreeSet, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ApplicationError, RusaintError, USaintSession,
+    application::{
+        USaintClientBuilder,
+        course_schedule::{
+            CourseScheduleApplication,
+            model::{Lecture, LectureCategory},
+        },
+        personal_course_schedule::{
+            PersonalCourseScheduleApplication,
+            model::{CourseScheduleEvent, PersonalCourseSchedule},
+        },
+    },
+    model::SemesterType,
+};
+
+/// 시간표 수업과 강의시간표 과목의 연결 결과
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+pub enum CatalogMatch {
+    /// 하나의 과목과 연결됨
+    Matched,
+    /// 조건에 맞는 과목이 여러 개라 하나로 결정할 수 없음
+    Ambiguous,
+    /// 강의명이 같은 과목은 있지만 교수명 또는 수업 시간이 일치하는 과목이 없음
+    PartiallyMatched,
+    /// 조건에 맞는 과목이 없음
+    Unmatched,
+}
+
+/// 강의시간표 과목 정보가 연결된 시간표 수업
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct EnrichedScheduleEntry {
+    event: CourseScheduleEvent,
+    matched: CatalogMatch,
+    candidates: Vec<Lecture>,
+}
+
+impl EnrichedScheduleEntry {
+    /// 시간표 수업을 반환합니다.
+    pub fn event(&self) -> &CourseScheduleEvent {
+        &self.event
+    }
+
+    /// 과목 연결 결과를 반환합니다.
+    pub fn matched(&self) -> CatalogMatch {
+        self.matched
+    }
+
+    /// 조건에 맞는 과목 목록을 반환합니다. 하나의 과목과 연결되었다면 해당 과목만 포함합니다.
+    /// [`CatalogMatch::PartiallyMatched`]라면 일치하지 않는 조건을 제외하고 좁힌 과목 목록을 포함합니다.
+    pub fn candidates(&self) -> &[Lecture] {
+        &self.candidates
+    }
+
+    /// 하나의 과목과 연결되었다면 해당 과목을 반환합니다.
+    pub fn lecture(&self) -> Option<&Lecture> {
+        match self.matched {
+            CatalogMatch::Matched => self.candidates.first(),
+            _ => None,
+        }
+    }
+}
+
+/// 강의시간표 과목 정보(과목번호, 분반, 학점, 강의계획서 등)가 연결된 개인 수업 시간표
+///
+/// 강의명이 같은 과목 중 교수명과 수업 시간이 일치하는 과목을 찾아 연결합니다.
+/// 강의명이 같은 과목이 없다면 [`CatalogMatch::Unmatched`]로, 교수명 또는 수업 시간이 일치하는 과목이 없다면 [`CatalogMatch::PartiallyMatched`]로,
+/// 모든 조건이 일치하는 과목이 여러 개라면 [`CatalogMatch::Ambiguous`]로 표시합니다.
+/// ### 예시
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// # use std::sync::Arc;
+/// # use rusaint::USaintSession;
+/// # use rusaint::application::personal_course_schedule::catalog::EnrichedSchedule;
+/// # use rusaint::model::SemesterType;
+/// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+/// let schedule = EnrichedSchedule::fetch(session, 2025, SemesterType::One).await.unwrap();
+/// for entry in schedule.entries() {
+///     if let Some(lecture) = entry.lecture() {
+///         println!("{} {:?} {:?}", lecture.code(), lecture.division(), lecture.credits());
+///     }
+/// }
+/// # })
+/// ```
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct EnrichedSchedule {
+    entries: Vec<EnrichedScheduleEntry>,
+}
+
+impl EnrichedSchedule {
+    /// 개인 수업 시간표와 같은 학기의 과목 목록을 연결합니다.
+    pub fn join(schedule: &PersonalCourseSchedule, lectures: &[Lecture]) -> Self {
+        let entries = schedule
+            .events()
+            .into_iter()
+            .map(|event| {
+                let (candidates, complete) = candidates(&event, lectures);
+                let matched = match candidates.len() {
+                    0 => CatalogMatch::Unmatched,
+                    _ if !complete => CatalogMatch::PartiallyMatched,
+                    1 => CatalogMatch::Matched,
+                    _ => CatalogMatch::Ambiguous,
+                };
+                EnrichedScheduleEntry {
+                    event,
+                    matched,
+                    candidates,
+                }
+            })
+            .collect();
+        Self { entries }
+    }
+
+    /// 주어진 세션으로 해당 학기의 개인 수업 시간표를 가져오고, 시간표의 각 강의명으로 강의시간표를 검색하여 연결합니다.
+    pub async fn fetch(
+        session: Arc<USaintSession>,
+        year: u32,
+        semester: SemesterType,
+    ) -> Result<Self, RusaintError> {
+        let mut schedule_app = USaintClientBuilder::new()
+            .session(session.clone())
+            .build_into::<PersonalCourseScheduleApplication>()
+            .await?;
+        let schedule = schedule_app.schedule(year, semester).await?;
+        let mut course_app = USaintClientBuilder::new()
+            .session(session)
+            .build_into::<CourseScheduleApplication>()
+            .await?;
+        let names: BTreeSet<String> = schedule
+            .events()
+            .iter()
+            .map(|event| event.information().name().to_string())
+            .collect();
+        let mut lectures = Vec::new();
+        for name in names {
+            let category = LectureCategory::find_by_lecture(&name);
+            match course_app.find_lectures(year, semester, &category).await {
+                Ok(found) => lectures.extend(found),
+                Err(RusaintError::ApplicationError(ApplicationError::NoLectureResult)) => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(Self::join(&schedule, &lectures))
+    }
+
+    /// 모든 수업 목록을 반환합니다.
+    pub fn entries(&self) -> &[EnrichedScheduleEntry] {
+        &self.entries
+    }
+
+    /// 과목을 하나로 결정할 수 없는 수업 목록을 반환합니다.
+    pub fn ambiguous(&self) -> Vec<&EnrichedScheduleEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.matched == CatalogMatch::Ambiguous)
+            .collect()
+    }
+
+    /// 교수명 또는 수업 시간이 일치하는 과목이 없는 수업 목록을 반환합니다.
+    pub fn partially_matched(&self) -> Vec<&EnrichedScheduleEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.matched == CatalogMatch::PartiallyMatched)
+            .collect()
+    }
+
+    /// 연결된 과목이 없는 수업 목록을 반환합니다.
+    pub fn unmatched(&self) -> Vec<&EnrichedScheduleEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.matched == CatalogMatch::Unmatched)
+            .collect()
+    }
+}
+
+fn normalize(str: &str) -> String {
+    str.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// 강의명이 같은 과목을 교수명과 수업 시간으로 좁힌 후보 목록과, 모든 조건이 일치했는지 여부를 반환합니다.
+fn candidates(event: &CourseScheduleEvent, lectures: &[Lecture]) -> (Vec<Lecture>, bool) {
+    let information = event.information();
+    let name = normalize(information.name());
+    let mut candidates: Vec<&Lecture> = lectures
+        .iter()
+        .filter(|lecture| normalize(lecture.name()) == name)
+        .collect();
+    let professor = information.professor().trim();
+    let mut complete = narrow(&mut candidates, |lecture| {
+        lecture
+            .professor()
+            .split(',')
+            .any(|other| other.trim() == professor)
+    });
+    let time = information.parsed_time().or_else(|| {
+        let period = information.period();
+        Some((period.start()?, period.end()?))
+    });
+    if let Some((start, end)) = time {
+        complete &= narrow(&mut candidates, |lecture| {
+            lecture.schedules().iter().any(|schedule| {
+                schedule.weekday() == event.weekday()
+                    && schedule.start() == start
+                    && schedule.end() == end
+            })
+        });
+    }
+    let mut seen = BTreeSet::new();
+    candidates.retain(|lecture| seen.insert((lecture.code(), lecture.division())));
+    (candidates.into_iter().cloned().collect(), complete)
+}
+
+/// 조건에 맞는 후보가 하나 이상 남는 경우에만 후보를 좁히고, 조건에 맞는 후보가 있었는지 반환합니다.
+fn narrow(candidates: &mut Vec<&Lecture>, predicate: impl Fn(&Lecture) -> bool) -> bool {
+    if candidates.iter().any(|lecture| predicate(lecture)) {
+        candidates.retain(|lecture| predicate(lecture));
+        true
+    } else {
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CatalogMatch, EnrichedSchedule};
+    use crate::application::{
+        course_schedule::model::{self, Lecture},
+        personal_course_schedule::model::PersonalCourseSchedule,
+    };
+
+    fn lecture(name: &str, division: &str, professor: &str, schedule_room: &str) -> Lecture {
+        model::test::lecture(&[
+            ("과목명", name),
+            ("분반", division),
+            ("교수명", professor),
+            ("강의시간(강의실)", schedule_room),
+        ])
+    }
+
+    #[test]
+    fn join_schedule_with_lectures() {
+        let cell = |str: &str| Some(str.to_string());
+        let rows = vec![
+            vec![cell(""), cell("월"), cell("화")],
+            vec![
+                cell("1교시"),
+                cell("자료구조\n홍길동\n09:00-10:15\n정보과학관 21203"),
+                cell("운영체제\n김철수\n09:00-10:15"),
+            ],
+            vec![
+                cell("2교시"),
+                cell("컴퓨터구조\n이영희\n10:30-11:45"),
+                cell("선형대수\n정민호\n10:30-11:45"),
+            ],
+        ];
+        let schedule = PersonalCourseSchedule::from_rows(rows, "TABLE").unwrap();
+        let lectures = vec![
+            lecture(
+                "자료구조",
+                "01",
+                "홍길동",
+                "월 09:00-10:15 (정보과학관 21203-홍길동)",
+            ),
+            lecture(
+                "자료구조",
+                "02",
+                "홍길동",
+                "수 09:00-10:15 (정보과학관 21203-홍길동)",
+            ),
+            lecture("운영체제", "01", "박민수", "목 09:00-10:15"),
+            lecture("운영체제", "02", "최지훈", "금 09:00-10:15"),
+            lecture("컴퓨터구조", "01", "이영희", "월 10:30-11:45"),
+            lecture("컴퓨터구조", "02", "이영희", "월 10:30-11:45"),
+        ];
+        let enriched = EnrichedSchedule::join(&schedule, &lectures);
+        assert_eq!(enriched.entries().len(), 4);
+        let matched = enriched.entries()[0].lecture().unwrap();
+        assert_eq!(matched.division(), Some("01"));
+        assert_eq!(enriched.ambiguous().len(), 1);
+        assert_eq!(enriched.ambiguous()[0].matched(), CatalogMatch::Ambiguous);
+        assert_eq!(
+            enriched.ambiguous()[0].event().information().name(),
+            "컴퓨터구조"
+        );
+        assert_eq!(enriched.ambiguous()[0].candidates().len(), 2);
+        let partial = enriched.partially_matched();
+        assert_eq!(partial.len(), 1);
+        assert_eq!(partial[0].event().information().name(), "운영체제");
+        assert_eq!(partial[0].candidates().len(), 2);
+        assert!(partial[0].lecture().is_none());
+        assert_eq!(enriched.unmatched().len(), 1);
+        assert_eq!(
+            enriched.unmatched()[0].event().information().name(),
+            "선형대수"
+        );
+    }
+}
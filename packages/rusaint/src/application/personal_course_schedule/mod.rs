@@ -1,6 +1,4 @@
-use std::collections::HashMap;
-
-use model::{CourseScheduleInformation, PersonalCourseSchedule, Weekday};
+use model::PersonalCourseSchedule;
 
 use super::{USaintApplication, USaintClient};
 use crate::application::utils::semester::get_selected_semester;
@@ -12,7 +10,7 @@ use wdpe::{
     body::Body,
     command::element::selection::{ComboBoxLSDataCommand, ComboBoxSelectEventCommand},
     define_elements,
-    element::{complex::SapTable, selection::ComboBox},
+    element::{complex::SapTable, definition::ElementDefinition, selection::ComboBox},
     error::{ElementError, WebDynproError},
 };
 
@@ -100,80 +98,9 @@ impl<'a> PersonalCourseScheduleApplication {
         match table {
             Ok(table) => {
                 let table_body = table.table()?;
-                let row_string: Vec<Vec<Option<String>>> =
+                let rows: Vec<Vec<Option<String>>> =
                     table_body.try_table_into::<Vec<Option<String>>>(&parser)?;
-                let mut schedule: HashMap<Weekday, Vec<CourseScheduleInformation>> =
-                    Default::default();
-                for row in row_string.into_iter().skip(1) {
-                    row.into_iter()
-                        .skip(1)
-                        .enumerate()
-                        .filter_map(|(col_idx, option)| option.map(|str| (col_idx, str)))
-                        .for_each(|(col_idx, str)| match col_idx {
-                            0 => {
-                                schedule.entry(Weekday::Mon).or_default();
-                                let mut iter = str.split("\n").peekable();
-                                while iter.peek().is_some() {
-                                    schedule
-                                        .get_mut(&Weekday::Mon)
-                                        .unwrap()
-                                        .push(CourseScheduleInformation::from_iter(&mut iter))
-                                }
-                            }
-                            1 => {
-                                schedule.entry(Weekday::Tue).or_default();
-                                let mut iter = str.split("\n").peekable();
-                                while iter.peek().is_some() {
-                                    schedule
-                                        .get_mut(&Weekday::Tue)
-                                        .unwrap()
-                                        .push(CourseScheduleInformation::from_iter(&mut iter))
-                                }
-                            }
-                            2 => {
-                                schedule.entry(Weekday::Wed).or_default();
-                                let mut iter = str.split("\n").peekable();
-                                while iter.peek().is_some() {
-                                    schedule
-                                        .get_mut(&Weekday::Wed)
-                                        .unwrap()
-                                        .push(CourseScheduleInformation::from_iter(&mut iter))
-                                }
-                            }
-                            3 => {
-                                schedule.entry(Weekday::Thu).or_default();
-                                let mut iter = str.split("\n").peekable();
-                                while iter.peek().is_some() {
-                                    schedule
-                                        .get_mut(&Weekday::Thu)
-                                        .unwrap()
-                                        .push(CourseScheduleInformation::from_iter(&mut iter))
-                                }
-                            }
-                            4 => {
-                                schedule.entry(Weekday::Fri).or_default();
-                                let mut iter = str.split("\n").peekable();
-                                while iter.peek().is_some() {
-                                    schedule
-                                        .get_mut(&Weekday::Fri)
-                                        .unwrap()
-                                        .push(CourseScheduleInformation::from_iter(&mut iter))
-                                }
-                            }
-                            5 => {
-                                schedule.entry(Weekday::Sat).or_default();
-                                let mut iter = str.split("\n").peekable();
-                                while iter.peek().is_some() {
-                                    schedule
-                                        .get_mut(&Weekday::Sat)
-                                        .unwrap()
-                                        .push(CourseScheduleInformation::from_iter(&mut iter))
-                                }
-                            }
-                            _ => {}
-                        });
-                }
-                Ok(PersonalCourseSchedule::new(schedule))
+                Ok(PersonalCourseSchedule::from_rows(rows, Self::TABLE.id())?)
             }
             Err(err) => match err {
                 WebDynproError::Element(ref el_err) => match el_err.as_ref() {
@@ -190,3 +117,9 @@ impl<'a> PersonalCourseScheduleApplication {
 
 /// [`PersonalCourseSchedule`] 애플리케이션에 사용되는 데이터
 pub mod model;
+
+/// 시간표를 iCalendar 형식으로 내보내는 기능
+pub mod calendar;
+
+/// 시간표 수업에 강의시간표의 과목 정보를 연결하는 기능
+pub mod catalog;
@@ -1,8 +1,10 @@
+use crate::model::TimeOfDay;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use wdpe::error::{ElementError, WebDynproError};
 
 /// 한 주의 요일을 표현합니다.
-#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
 pub enum Weekday {
     /// 월요일
@@ -21,6 +23,79 @@ pub enum Weekday {
     Sun = 6,
 }
 
+impl Weekday {
+    /// `월`, `화요일`과 같은 한국어 요일 표기를 [`Weekday`]로 변환합니다.
+    pub(crate) fn from_korean(str: &str) -> Option<Weekday> {
+        match str.trim().trim_end_matches("요일") {
+            "월" => Some(Weekday::Mon),
+            "화" => Some(Weekday::Tue),
+            "수" => Some(Weekday::Wed),
+            "목" => Some(Weekday::Thu),
+            "금" => Some(Weekday::Fri),
+            "토" => Some(Weekday::Sat),
+            "일" => Some(Weekday::Sun),
+            _ => None,
+        }
+    }
+}
+
+/// 시간표의 행 머리글로 표시되는 교시 정보입니다.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct SchedulePeriod {
+    label: String,
+    period: Option<u32>,
+    start: Option<TimeOfDay>,
+    end: Option<TimeOfDay>,
+}
+
+impl SchedulePeriod {
+    /// `1교시\n09:00-09:50`과 같은 행 머리글에서 교시 정보를 읽어옵니다.
+    /// 교시 번호는 `교시` 바로 앞의 숫자만 읽으며, 시각만 있는 머리글에서는 읽지 않습니다.
+    pub(crate) fn from_header(header: &str) -> Self {
+        let label = header.split_whitespace().collect::<Vec<_>>().join(" ");
+        let period = label.split_once("교시").and_then(|(before, _)| {
+            before
+                .trim_end()
+                .rsplit(|c: char| !c.is_ascii_digit())
+                .next()
+                .and_then(|digits| digits.parse().ok())
+        });
+        let mut times = label
+            .split(|c: char| !(c.is_ascii_digit() || c == ':'))
+            .filter(|token| token.contains(':'))
+            .filter_map(|token| token.parse::<TimeOfDay>().ok());
+        let start = times.next();
+        let end = times.next();
+        Self {
+            label,
+            period,
+            start,
+            end,
+        }
+    }
+
+    /// 행 머리글에 표시된 문자열을 반환합니다.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// 교시 번호를 반환합니다. 머리글에 교시 번호가 없다면 `None`을 반환합니다.
+    pub fn period(&self) -> Option<u32> {
+        self.period
+    }
+
+    /// 교시의 시작 시각을 반환합니다.
+    pub fn start(&self) -> Option<TimeOfDay> {
+        self.start
+    }
+
+    /// 교시의 종료 시각을 반환합니다.
+    pub fn end(&self) -> Option<TimeOfDay> {
+        self.end
+    }
+}
+
 /// 개인의 수업 시간표 정보를 조회합니다.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
@@ -29,27 +104,38 @@ pub struct PersonalCourseSchedule {
 }
 
 /// 강의의 시간표 정보입니다.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
 pub struct CourseScheduleInformation {
     name: String,
     professor: String,
     time: String,
     classroom: String,
+    #[serde(default)]
+    period: SchedulePeriod,
 }
 
 impl CourseScheduleInformation {
+    /// 셀 문자열에서 강의 하나를 읽어옵니다. 남은 강의가 없다면 `Ok(None)`을, 강의 정보가 불완전하다면 `Err`을 반환합니다.
     pub(crate) fn from_iter<'a>(
         iter: &mut impl Iterator<Item = &'a str>,
-    ) -> CourseScheduleInformation {
-        let mut iter = iter.skip_while(|s| s.is_empty());
+        period: &SchedulePeriod,
+    ) -> Result<Option<CourseScheduleInformation>, String> {
         // Consume empty strings at start
-        CourseScheduleInformation {
-            name: iter.next().unwrap().to_string(),
-            professor: iter.next().unwrap().to_string(),
-            time: iter.next().unwrap().to_string(),
+        let mut iter = iter.map(str::trim).skip_while(|s| s.is_empty());
+        let Some(name) = iter.next() else {
+            return Ok(None);
+        };
+        let (Some(professor), Some(time)) = (iter.next(), iter.next()) else {
+            return Err(name.to_string());
+        };
+        Ok(Some(CourseScheduleInformation {
+            name: name.to_string(),
+            professor: professor.to_string(),
+            time: time.to_string(),
             classroom: iter.next().unwrap_or("").to_string(),
-        }
+            period: period.clone(),
+        }))
     }
 
     /// 강의명을 반환합니다.
@@ -67,10 +153,41 @@ impl CourseScheduleInformation {
         &self.time
     }
 
+    /// 강의 시작 시각과 종료 시각을 반환합니다. 변환할 수 없다면 `None`을 반환합니다.
+    pub fn parsed_time(&self) -> Option<(TimeOfDay, TimeOfDay)> {
+        let (start, end) = self.time.split_once('-')?;
+        Some((start.trim().parse().ok()?, end.trim().parse().ok()?))
+    }
+
     /// 강의실을 반환합니다.
     pub fn classroom(&self) -> &str {
         &self.classroom
     }
+
+    /// 강의가 표시된 행의 교시 정보를 반환합니다.
+    pub fn period(&self) -> &SchedulePeriod {
+        &self.period
+    }
+}
+
+/// 요일이 지정된 하나의 수업 일정입니다.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct CourseScheduleEvent {
+    weekday: Weekday,
+    information: CourseScheduleInformation,
+}
+
+impl CourseScheduleEvent {
+    /// 수업 요일을 반환합니다.
+    pub fn weekday(&self) -> Weekday {
+        self.weekday
+    }
+
+    /// 강의의 시간표 정보를 반환합니다.
+    pub fn information(&self) -> &CourseScheduleInformation {
+        &self.information
+    }
 }
 
 impl PersonalCourseSchedule {
@@ -78,8 +195,164 @@ impl PersonalCourseSchedule {
         Self { schedule }
     }
 
+    /// 시간표 테이블의 각 행 문자열로 시간표를 만듭니다.
+    ///
+    /// 첫 행은 요일 머리글, 각 행의 첫 열은 교시 머리글로 취급합니다.
+    /// 머리글에서 요일을 읽을 수 없다면 월요일부터 순서대로 요일을 지정합니다.
+    pub(crate) fn from_rows(
+        rows: Vec<Vec<Option<String>>>,
+        table_id: &str,
+    ) -> Result<Self, WebDynproError> {
+        let mut rows = rows.into_iter();
+        let header = rows.next().unwrap_or_default();
+        let header_weekdays: Vec<Option<Weekday>> = header
+            .iter()
+            .skip(1)
+            .map(|cell| cell.as_deref().and_then(Weekday::from_korean))
+            .collect();
+        let has_header = header_weekdays.iter().any(Option::is_some);
+        let weekday_of = |col_idx: usize| -> Option<Weekday> {
+            if has_header {
+                header_weekdays.get(col_idx).copied().flatten()
+            } else {
+                const POSITIONAL: [Weekday; 7] = [
+                    Weekday::Mon,
+                    Weekday::Tue,
+                    Weekday::Wed,
+                    Weekday::Thu,
+                    Weekday::Fri,
+                    Weekday::Sat,
+                    Weekday::Sun,
+                ];
+                POSITIONAL.get(col_idx).copied()
+            }
+        };
+        let mut schedule: HashMap<Weekday, Vec<CourseScheduleInformation>> = HashMap::new();
+        for row in rows {
+            let mut cells = row.into_iter();
+            let period = SchedulePeriod::from_header(&cells.next().flatten().unwrap_or_default());
+            for (col_idx, cell) in cells.enumerate() {
+                let Some(cell) = cell else {
+                    continue;
+                };
+                let Some(weekday) = weekday_of(col_idx) else {
+                    continue;
+                };
+                let entries = schedule.entry(weekday).or_default();
+                let mut iter = cell.split('\n');
+                while let Some(information) =
+                    CourseScheduleInformation::from_iter(&mut iter, &period).map_err(|name| {
+                        ElementError::InvalidContent {
+                            element: table_id.to_string(),
+                            content: format!("incomplete schedule entry: {name}"),
+                        }
+                    })?
+                {
+                    entries.push(information);
+                }
+            }
+        }
+        Ok(Self::new(schedule))
+    }
+
     /// 시간표 배열을 반환합니다.
     pub fn schedule(&self) -> &HashMap<Weekday, Vec<CourseScheduleInformation>> {
         &self.schedule
     }
+
+    /// 시간표를 요일, 시작 시각 순으로 정렬된 수업 일정 목록으로 반환합니다.
+    ///
+    /// 여러 교시에 걸쳐 표시된 같은 강의는 하나의 일정으로 합칩니다.
+    pub fn events(&self) -> Vec<CourseScheduleEvent> {
+        let mut events: Vec<CourseScheduleEvent> = Vec::new();
+        for (weekday, informations) in &self.schedule {
+            for information in informations {
+                let duplicated = events.iter().any(|event| {
+                    event.weekday == *weekday
+                        && event.information.name == information.name
+                        && event.information.time == information.time
+                        && event.information.classroom == information.classroom
+                });
+                if !duplicated {
+                    events.push(CourseScheduleEvent {
+                        weekday: *weekday,
+                        information: information.clone(),
+                    });
+                }
+            }
+        }
+        events.sort_by(|a, b| {
+            let start = |event: &CourseScheduleEvent| {
+                event
+                    .information
+                    .parsed_time()
+                    .map(|(start, _)| start)
+                    .or(event.information.period.start)
+            };
+            a.weekday
+                .cmp(&b.weekday)
+                .then_with(|| start(a).cmp(&start(b)))
+                .then_with(|| a.information.name.cmp(&b.information.name))
+        });
+        events
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{PersonalCourseSchedule, SchedulePeriod, Weekday};
+    use crate::model::TimeOfDay;
+
+    fn cell(str: &str) -> Option<String> {
+        Some(str.to_string())
+    }
+
+    #[test]
+    fn parse_rows_from_header() {
+        let rows = vec![
+            vec![cell("교시"), cell("월"), cell("화"), cell("일")],
+            vec![
+                cell("1교시\n09:00-09:50"),
+                cell("자료구조\n홍길동\n09:00-10:15\n정보과학관 21203"),
+                None,
+                cell("채플\n김철수\n09:00-09:50"),
+            ],
+            vec![
+                cell("2교시\n10:00-10:50"),
+                cell("자료구조\n홍길동\n09:00-10:15\n정보과학관 21203"),
+                None,
+                None,
+            ],
+        ];
+        let schedule = PersonalCourseSchedule::from_rows(rows, "TABLE").unwrap();
+        assert_eq!(schedule.schedule()[&Weekday::Mon].len(), 2);
+        let sunday = &schedule.schedule()[&Weekday::Sun][0];
+        assert_eq!(sunday.classroom(), "");
+        assert_eq!(sunday.period().period(), Some(1));
+        assert_eq!(sunday.period().start(), TimeOfDay::new(9, 0));
+        assert_eq!(sunday.period().end(), TimeOfDay::new(9, 50));
+        let events = schedule.events();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].weekday(), Weekday::Mon);
+        assert_eq!(events[1].weekday(), Weekday::Sun);
+
+        let malformed = vec![
+            vec![cell(""), cell("월")],
+            vec![cell("1교시"), cell("자료구조\n홍길동")],
+        ];
+        assert!(PersonalCourseSchedule::from_rows(malformed, "TABLE").is_err());
+    }
+
+    #[test]
+    fn period_from_header() {
+        assert_eq!(
+            SchedulePeriod::from_header("1교시\n09:00-09:50").period(),
+            Some(1)
+        );
+        assert_eq!(SchedulePeriod::from_header("10 교시").period(), Some(10));
+        let times_only = SchedulePeriod::from_header("09:00-09:50");
+        assert_eq!(times_only.period(), None);
+        assert_eq!(times_only.start(), TimeOfDay::new(9, 0));
+        assert_eq!(SchedulePeriod::from_header("교시").period(), None);
+    }
 }
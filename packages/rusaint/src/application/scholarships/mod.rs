@@ -1,6 +1,7 @@
 use super::{USaintApplication, USaintClient};
-use crate::RusaintError;
 use crate::application::scholarships::model::Scholarship;
+use crate::application::scholarships::summary::{ScholarshipFilter, ScholarshipSummary};
+use crate::{ApplicationError, RusaintError};
 use wdpe::body::Body;
 use wdpe::element::parser::ElementParser;
 
@@ -31,7 +32,33 @@ impl ScholarshipsApplication {
         let parser = ElementParser::new(self.body());
         Scholarship::with_parser(&parser)
     }
+
+    /// 장학금 수혜 내역 중 필터의 조건을 만족하는 장학금만 가져옵니다. 수혜 내역이 없다면 빈 목록을 반환합니다.
+    pub async fn filtered_scholarships(
+        &mut self,
+        filter: &ScholarshipFilter,
+    ) -> Result<Vec<Scholarship>, RusaintError> {
+        match self.scholarships().await {
+            Ok(scholarships) => Ok(filter.apply(scholarships)),
+            Err(RusaintError::ApplicationError(ApplicationError::NoScholarshipInformation)) => {
+                Ok(Vec::new())
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// 필터의 조건을 만족하는 장학금의 학기별, 년도별 합계와 교체 관계를 계산합니다.
+    pub async fn scholarship_summary(
+        &mut self,
+        filter: &ScholarshipFilter,
+    ) -> Result<ScholarshipSummary, RusaintError> {
+        let scholarships = self.filtered_scholarships(filter).await?;
+        Ok(ScholarshipSummary::new(&scholarships))
+    }
 }
 
 /// [`ScholarshipsApplication`] 애플리케이션에 사용되는 데이터
 pub mod model;
+
+/// 장학금 수혜 내역의 필터와 합계
+pub mod summary;
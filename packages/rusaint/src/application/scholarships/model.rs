@@ -8,7 +8,11 @@ use serde::{
 };
 
 use crate::application::utils::de_with::deserialize_u32_string;
-use crate::{RusaintError, error::ApplicationError, model::SemesterType};
+use crate::{
+    RusaintError,
+    error::ApplicationError,
+    model::{Date, SemesterType, parse_date},
+};
 use wdpe::command::WebDynproCommandExecutor;
 use wdpe::element::parser::ElementParser;
 use wdpe::{
@@ -87,13 +91,13 @@ impl Scholarship {
         }
         let table = parser.read(SapTableBodyCommand::new(TABLE))?;
         let Some(first_row) = table.iter().next() else {
-            return Err(ApplicationError::NoChapelInformation.into());
+            return Err(ApplicationError::NoScholarshipInformation.into());
         };
         if let Some(Ok(SapTableCellWrapper::Normal(cell))) = first_row.iter_value(parser).next() {
             if let Some(ElementDefWrapper::TextView(tv_def)) = cell.content() {
                 if let Ok(tv) = parser.element_from_def(&tv_def) {
                     if tv.text().contains("없습니다.") {
-                        return Err(ApplicationError::NoChapelInformation.into());
+                        return Err(ApplicationError::NoScholarshipInformation.into());
                     }
                 }
             }
@@ -136,6 +140,11 @@ impl Scholarship {
         &self.processed_at
     }
 
+    /// 처리일자를 [`Date`]로 변환하여 반환합니다.
+    pub fn parsed_processed_at(&self) -> Option<Date> {
+        parse_date(self.processed_at())
+    }
+
     /// 선발금액을 반환합니다.
     pub fn selected_amount(&self) -> u64 {
         self.selected_amount
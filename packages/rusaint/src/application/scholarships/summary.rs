@@ -0,0 +1,381 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{application::scholarships::model::Scholarship, model::SemesterType};
+
+/// 장학금 수혜 내역을 조건에 따라 걸러내는 필터
+///
+/// 지정하지 않은 조건은 검사하지 않으며, 지정한 모든 조건을 만족하는 장학금만 남깁니다.
+/// ### 예시
+/// ```no_run
+/// # tokio_test::block_on(async {
+/// # use std::sync::Arc;
+/// # use rusaint::USaintSession;
+/// # use rusaint::application::USaintClientBuilder;
+/// # use rusaint::application::scholarships::{ScholarshipsApplication, summary::ScholarshipFilter};
+/// # let session = Arc::new(USaintSession::with_password("20212345", "password").await.unwrap());
+/// let mut app = USaintClientBuilder::new().session(session).build_into::<ScholarshipsApplication>().await.unwrap();
+/// let filter = ScholarshipFilter::new().from_year(2023).status("지급");
+/// let summary = app.scholarship_summary(&filter).await.unwrap();
+/// println!("{}", summary.total().received_amount());
+/// # })
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ScholarshipFilter {
+    /// 가장 이른 수혜 년도
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    from_year: Option<u32>,
+    /// 가장 늦은 수혜 년도
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    to_year: Option<u32>,
+    /// 수혜 학기 목록. 비어 있다면 학기를 검사하지 않습니다.
+    #[cfg_attr(feature = "uniffi", uniffi(default = []))]
+    semesters: Vec<SemesterType>,
+    /// 처리상태
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    status: Option<String>,
+    /// 지급방법
+    #[cfg_attr(feature = "uniffi", uniffi(default = None))]
+    receive_type: Option<String>,
+}
+
+impl ScholarshipFilter {
+    /// 아무 조건도 없는 새로운 [`ScholarshipFilter`]를 만듭니다.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 주어진 년도 이후에 수혜한 장학금만 남깁니다.
+    pub fn from_year(mut self, year: u32) -> Self {
+        self.from_year = Some(year);
+        self
+    }
+
+    /// 주어진 년도 이전에 수혜한 장학금만 남깁니다.
+    pub fn to_year(mut self, year: u32) -> Self {
+        self.to_year = Some(year);
+        self
+    }
+
+    /// 주어진 학기에 수혜한 장학금만 남깁니다.
+    pub fn semesters(mut self, semesters: &[SemesterType]) -> Self {
+        self.semesters = semesters.to_vec();
+        self
+    }
+
+    /// 처리상태가 주어진 값과 같은 장학금만 남깁니다.
+    pub fn status(mut self, status: &str) -> Self {
+        self.status = Some(status.to_string());
+        self
+    }
+
+    /// 지급방법이 주어진 값과 같은 장학금만 남깁니다.
+    pub fn receive_type(mut self, receive_type: &str) -> Self {
+        self.receive_type = Some(receive_type.to_string());
+        self
+    }
+
+    /// 장학금이 이 필터의 모든 조건을 만족하는지 확인합니다.
+    pub fn matches(&self, scholarship: &Scholarship) -> bool {
+        self.from_year.is_none_or(|year| scholarship.year() >= year)
+            && self.to_year.is_none_or(|year| scholarship.year() <= year)
+            && (self.semesters.is_empty() || self.semesters.contains(&scholarship.semester()))
+            && self
+                .status
+                .as_deref()
+                .is_none_or(|status| scholarship.status().trim() == status.trim())
+            && self
+                .receive_type
+                .as_deref()
+                .is_none_or(|receive_type| scholarship.receive_type().trim() == receive_type.trim())
+    }
+
+    /// 조건을 만족하는 장학금만 남겨 반환합니다.
+    pub fn apply(&self, scholarships: impl IntoIterator<Item = Scholarship>) -> Vec<Scholarship> {
+        scholarships
+            .into_iter()
+            .filter(|scholarship| self.matches(scholarship))
+            .collect()
+    }
+}
+
+/// 장학금 금액 합계
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ScholarshipTotals {
+    /// 장학금 수
+    count: u32,
+    /// 실수혜금액 합계
+    received_amount: u64,
+    /// 선발금액 합계
+    selected_amount: u64,
+    /// 환수금액 합계
+    refunded_amount: u64,
+    /// 교체금액 합계
+    replaced_amount: u64,
+}
+
+impl ScholarshipTotals {
+    fn add(&mut self, scholarship: &Scholarship) {
+        self.count += 1;
+        self.received_amount += scholarship.received_amount();
+        self.selected_amount += scholarship.selected_amount();
+        self.refunded_amount += scholarship.refunded_amount();
+        self.replaced_amount += scholarship.replaced_amount();
+    }
+
+    /// 장학금 수를 반환합니다.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// 실수혜금액 합계를 반환합니다.
+    pub fn received_amount(&self) -> u64 {
+        self.received_amount
+    }
+
+    /// 선발금액 합계를 반환합니다.
+    pub fn selected_amount(&self) -> u64 {
+        self.selected_amount
+    }
+
+    /// 환수금액 합계를 반환합니다.
+    pub fn refunded_amount(&self) -> u64 {
+        self.refunded_amount
+    }
+
+    /// 교체금액 합계를 반환합니다.
+    pub fn replaced_amount(&self) -> u64 {
+        self.replaced_amount
+    }
+}
+
+/// 한 학기의 장학금 합계
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ScholarshipSemesterSummary {
+    year: u32,
+    semester: SemesterType,
+    totals: ScholarshipTotals,
+}
+
+impl ScholarshipSemesterSummary {
+    /// 수혜 년도를 반환합니다.
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// 수혜 학기를 반환합니다.
+    pub fn semester(&self) -> SemesterType {
+        self.semester
+    }
+
+    /// 해당 학기의 금액 합계를 반환합니다.
+    pub fn totals(&self) -> ScholarshipTotals {
+        self.totals
+    }
+}
+
+/// 한 해의 장학금 합계
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ScholarshipYearSummary {
+    year: u32,
+    totals: ScholarshipTotals,
+}
+
+impl ScholarshipYearSummary {
+    /// 수혜 년도를 반환합니다.
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// 해당 년도의 금액 합계를 반환합니다.
+    pub fn totals(&self) -> ScholarshipTotals {
+        self.totals
+    }
+}
+
+/// 다른 장학금으로 교체된 장학금
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ScholarshipReplacement {
+    original: Scholarship,
+    replacement: Option<Scholarship>,
+}
+
+impl ScholarshipReplacement {
+    /// 교체된 장학금을 반환합니다.
+    pub fn original(&self) -> &Scholarship {
+        &self.original
+    }
+
+    /// 같은 학기의 수혜 내역 중 교체장학금명과 이름이 같은 장학금을 반환합니다. 찾을 수 없다면 `None`을 반환합니다.
+    pub fn replacement(&self) -> Option<&Scholarship> {
+        self.replacement.as_ref()
+    }
+}
+
+/// 장학금 수혜 내역의 학기별, 년도별 합계와 교체 관계
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct ScholarshipSummary {
+    semesters: Vec<ScholarshipSemesterSummary>,
+    years: Vec<ScholarshipYearSummary>,
+    total: ScholarshipTotals,
+    replacements: Vec<ScholarshipReplacement>,
+}
+
+impl ScholarshipSummary {
+    /// 장학금 수혜 내역의 합계와 교체 관계를 계산합니다.
+    pub fn new(scholarships: &[Scholarship]) -> Self {
+        let mut semesters: BTreeMap<(u32, SemesterType), ScholarshipTotals> = BTreeMap::new();
+        let mut years: BTreeMap<u32, ScholarshipTotals> = BTreeMap::new();
+        let mut total = ScholarshipTotals::default();
+        for scholarship in scholarships {
+            semesters
+                .entry((scholarship.year(), scholarship.semester()))
+                .or_default()
+                .add(scholarship);
+            years
+                .entry(scholarship.year())
+                .or_default()
+                .add(scholarship);
+            total.add(scholarship);
+        }
+        let replacements = scholarships
+            .iter()
+            .filter(|scholarship| !scholarship.replaced_by().trim().is_empty())
+            .map(|original| {
+                let replacement = scholarships
+                    .iter()
+                    .find(|other| {
+                        other.year() == original.year()
+                            && other.semester() == original.semester()
+                            && other.name().trim() == original.replaced_by().trim()
+                    })
+                    .cloned();
+                ScholarshipReplacement {
+                    original: original.clone(),
+                    replacement,
+                }
+            })
+            .collect();
+        Self {
+            semesters: semesters
+                .into_iter()
+                .map(|((year, semester), totals)| ScholarshipSemesterSummary {
+                    year,
+                    semester,
+                    totals,
+                })
+                .collect(),
+            years: years
+                .into_iter()
+                .map(|(year, totals)| ScholarshipYearSummary { year, totals })
+                .collect(),
+            total,
+            replacements,
+        }
+    }
+
+    /// 학기 순으로 정렬된 학기별 합계를 반환합니다.
+    pub fn semesters(&self) -> &[ScholarshipSemesterSummary] {
+        &self.semesters
+    }
+
+    /// 년도 순으로 정렬된 년도별 합계를 반환합니다.
+    pub fn years(&self) -> &[ScholarshipYearSummary] {
+        &self.years
+    }
+
+    /// 전체 합계를 반환합니다.
+    pub fn total(&self) -> ScholarshipTotals {
+        self.total
+    }
+
+    /// 다른 장학금으로 교체된 장학금 목록을 반환합니다.
+    pub fn replacements(&self) -> &[ScholarshipReplacement] {
+        &self.replacements
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ScholarshipFilter, ScholarshipSummary};
+    use crate::{
+        application::{scholarships::model::Scholarship, utils::from_fields},
+        model::SemesterType,
+    };
+
+    fn scholarship(
+        year: &str,
+        semester: &str,
+        name: &str,
+        received: &str,
+        status: &str,
+        replaced_by: &str,
+    ) -> Scholarship {
+        let replaced_amount = if replaced_by.is_empty() {
+            "0"
+        } else {
+            "1,000,000"
+        };
+        from_fields(&[
+            ("학년", year),
+            ("학기", semester),
+            ("장학금명", name),
+            ("실수혜금액", received),
+            ("지급방법", "등록금감면"),
+            ("처리상태", status),
+            ("처리일자", "2024.03.01"),
+            ("선발금액", "1,000,000"),
+            ("환수금액", "0"),
+            ("교체금액", replaced_amount),
+            ("교체장학금명", replaced_by),
+            ("탈락사유", ""),
+            ("비고", ""),
+            ("근로부서", ""),
+        ])
+    }
+
+    #[test]
+    fn summarize_scholarships() {
+        let scholarships = vec![
+            scholarship(
+                "2024",
+                "1 학기",
+                "성적우수장학금",
+                "0",
+                "교체",
+                "국가장학금",
+            ),
+            scholarship("2024", "1 학기", "국가장학금", "1,000,000", "지급", ""),
+            scholarship("2024", "2 학기", "국가장학금", "1,500,000", "지급", ""),
+            scholarship("2023", "2 학기", "근로장학금", "500,000", "지급", ""),
+        ];
+        let filtered = ScholarshipFilter::new()
+            .from_year(2024)
+            .semesters(&[SemesterType::One])
+            .apply(scholarships.clone());
+        assert_eq!(filtered.len(), 2);
+        let paid = ScholarshipFilter::new()
+            .status("지급")
+            .apply(scholarships.clone());
+        assert_eq!(paid.len(), 3);
+
+        let summary = ScholarshipSummary::new(&scholarships);
+        assert_eq!(summary.semesters().len(), 3);
+        assert_eq!(summary.semesters()[0].year(), 2023);
+        assert_eq!(summary.semesters()[1].totals().count(), 2);
+        assert_eq!(summary.semesters()[1].totals().received_amount(), 1_000_000);
+        assert_eq!(summary.years()[1].totals().received_amount(), 2_500_000);
+        assert_eq!(summary.total().selected_amount(), 4_000_000);
+        assert_eq!(summary.total().replaced_amount(), 1_000_000);
+        let replacement = &summary.replacements()[0];
+        assert_eq!(replacement.original().name(), "성적우수장학금");
+        assert_eq!(replacement.replacement().unwrap().name(), "국가장학금");
+    }
+}
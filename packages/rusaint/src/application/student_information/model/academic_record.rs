@@ -7,6 +7,7 @@ use serde::{
 
 use crate::application::utils::sap_table::try_table_into_with_scroll;
 use crate::application::{USaintClient, student_information::StudentInformationApplication};
+use crate::model::{Date, parse_date};
 use wdpe::command::WebDynproCommandExecutor;
 use wdpe::element::parser::ElementParser;
 use wdpe::{
@@ -84,6 +85,11 @@ impl StudentAcademicRecord {
         &self.end_date
     }
 
+    /// 종료일을 [`Date`]로 변환하여 반환합니다.
+    pub fn parsed_end_date(&self) -> Option<Date> {
+        parse_date(self.end_date())
+    }
+
     /// 학년도를 반환합니다.
     pub fn year(&self) -> &str {
         &self.year
@@ -109,10 +115,20 @@ impl StudentAcademicRecord {
         &self.process_date
     }
 
+    /// 처리일자를 [`Date`]로 변환하여 반환합니다.
+    pub fn parsed_process_date(&self) -> Option<Date> {
+        parse_date(self.process_date())
+    }
+
     /// 시작일을 반환합니다.
     pub fn start_date(&self) -> &str {
         &self.start_date
     }
+
+    /// 시작일을 [`Date`]로 변환하여 반환합니다.
+    pub fn parsed_start_date(&self) -> Option<Date> {
+        parse_date(self.start_date())
+    }
 }
 
 impl<'a> FromSapTable<'a> for StudentAcademicRecord {
@@ -7,6 +7,7 @@ use wdpe::{
 };
 
 use crate::application::utils::input_field::InputFieldExt as _;
+use crate::model::{Date, parse_date};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
@@ -115,6 +116,11 @@ impl<'a> StudentGraduation {
         &self.graduation_date
     }
 
+    /// 학위수여일을 [`Date`]로 변환하여 반환합니다.
+    pub fn parsed_graduation_date(&self) -> Option<Date> {
+        parse_date(self.graduation_date())
+    }
+
     /// 학위번호를 반환합니다.
     pub fn academic_degree_number(&self) -> u32 {
         self.academic_degree_number
@@ -275,6 +275,11 @@ impl<'a> StudentInformation {
         &self.apply_date
     }
 
+    /// 입학년월일을 [`Date`]로 변환하여 반환합니다.
+    pub fn parsed_apply_date(&self) -> Option<Date> {
+        parse_date(self.apply_date())
+    }
+
     /// 입학 당시 단과 대학을 반환합니다.
     pub fn applied_collage(&self) -> &str {
         &self.applied_collage
@@ -331,3 +336,4 @@ use wdpe::element::parser::ElementParser;
 pub use work::StudentWorkInformation;
 
 use crate::application::utils::input_field::InputFieldExt as _;
+use crate::model::{Date, parse_date};
@@ -1,3 +1,4 @@
+use crate::model::{Date, parse_date};
 use serde::{Deserialize, Serialize};
 use wdpe::command::WebDynproCommandExecutor;
 use wdpe::element::parser::ElementParser;
@@ -104,10 +105,20 @@ impl<'a> StudentTeachingMajorInformation {
         self.initiation_date.as_deref()
     }
 
+    /// 선발일자를 [`Date`]로 변환하여 반환합니다.
+    pub fn parsed_initiation_date(&self) -> Option<Date> {
+        self.initiation_date().and_then(parse_date)
+    }
+
     /// 교원자격증 발급일자를 반환합니다.
     pub fn qualification_date(&self) -> Option<&str> {
         self.qualification_date.as_deref()
     }
+
+    /// 교원자격증 발급일자를 [`Date`]로 변환하여 반환합니다.
+    pub fn parsed_qualification_date(&self) -> Option<Date> {
+        self.qualification_date().and_then(parse_date)
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -160,6 +171,11 @@ impl<'a> StudentTeachingPluralMajorInformation {
     pub fn qualification_date(&self) -> Option<&str> {
         self.qualification_date.as_deref()
     }
+
+    /// 교원자격증 발급일자를 [`Date`]로 변환하여 반환합니다.
+    pub fn parsed_qualification_date(&self) -> Option<Date> {
+        self.qualification_date().and_then(parse_date)
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -209,6 +225,11 @@ impl<'a> StudentLifelongInformation {
         self.apply_date.as_deref()
     }
 
+    /// 신청일자를 [`Date`]로 변환하여 반환합니다.
+    pub fn parsed_apply_date(&self) -> Option<Date> {
+        self.apply_date().and_then(parse_date)
+    }
+
     /// 자격구분을 반환합니다.
     pub fn lifelong_type(&self) -> Option<&str> {
         self.lifelong_type.as_deref()
@@ -223,6 +244,11 @@ impl<'a> StudentLifelongInformation {
     pub fn qualification_date(&self) -> Option<&str> {
         self.qualification_date.as_deref()
     }
+
+    /// 자격증 발급일자를 [`Date`]로 변환하여 반환합니다.
+    pub fn parsed_qualification_date(&self) -> Option<Date> {
+        self.qualification_date().and_then(parse_date)
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -237,7 +263,7 @@ pub struct StudentForignStudyInformation {
 impl<'a> StudentForignStudyInformation {
     // 7+1 프로그램
     define_elements! {
-      // 신청일자
+      // 승인일자
       APPRODATE: InputField<'a> = "ZCMW1001.ID_0001:VIW_DEFAULT.APPRODATE";
       // 인증서번호
       AUTHEN_NO: InputField<'a> = "ZCMW1001.ID_0001:VIW_DEFAULT.AUTHEN_NO";
@@ -261,11 +287,16 @@ impl<'a> StudentForignStudyInformation {
         })
     }
 
-    /// 신청일자를 반환합니다.
+    /// 승인일자를 반환합니다.
     pub fn approval_date(&self) -> Option<&str> {
         self.approval_date.as_deref()
     }
 
+    /// 승인일자를 [`Date`]로 변환하여 반환합니다.
+    pub fn parsed_approval_date(&self) -> Option<Date> {
+        self.approval_date().and_then(parse_date)
+    }
+
     /// 인증서번호를 반환합니다.
     pub fn authentication_number(&self) -> Option<&str> {
         self.authentication_number.as_deref()
@@ -275,4 +306,9 @@ impl<'a> StudentForignStudyInformation {
     pub fn issue_date(&self) -> Option<&str> {
         self.issue_date.as_deref()
     }
+
+    /// 발급일자를 [`Date`]로 변환하여 반환합니다.
+    pub fn parsed_issue_date(&self) -> Option<Date> {
+        self.issue_date().and_then(parse_date)
+    }
 }
@@ -1,4 +1,5 @@
 use crate::application::{USaintClient, student_information::StudentInformationApplication};
+use crate::model::{Date, parse_date};
 
 use serde::{Deserialize, Serialize};
 use wdpe::command::WebDynproCommandExecutor;
@@ -141,6 +142,11 @@ impl<'a> StudentReligion {
         self.start_date.as_deref()
     }
 
+    /// 신앙시작일을 [`Date`]로 변환하여 반환합니다.
+    pub fn parsed_start_date(&self) -> Option<Date> {
+        self.start_date().and_then(parse_date)
+    }
+
     /// 출석교회를 반환합니다.
     pub fn church(&self) -> Option<&str> {
         self.church.as_deref()
@@ -186,6 +192,11 @@ impl<'a> StudentReligion {
         self.baptism_date.as_deref()
     }
 
+    /// 세례일자를 [`Date`]로 변환하여 반환합니다.
+    pub fn parsed_baptism_date(&self) -> Option<Date> {
+        self.baptism_date().and_then(parse_date)
+    }
+
     /// 세례교회를 반환합니다.
     pub fn baptism_church(&self) -> Option<&str> {
         self.baptism_church.as_deref()
@@ -7,6 +7,7 @@ use serde::{
 
 use crate::application::utils::sap_table::try_table_into_with_scroll;
 use crate::application::{USaintClient, student_information::StudentInformationApplication};
+use crate::model::{Date, parse_date};
 use wdpe::command::WebDynproCommandExecutor;
 use wdpe::element::parser::ElementParser;
 use wdpe::{
@@ -90,6 +91,11 @@ impl StudentTransferRecord {
         &self.admission_date
     }
 
+    /// 입학일자를 [`Date`]로 변환하여 반환합니다.
+    pub fn parsed_admission_date(&self) -> Option<Date> {
+        parse_date(self.admission_date())
+    }
+
     /// 편입학년을 반환합니다.
     pub fn admission_grade(&self) -> &str {
         &self.admission_grade
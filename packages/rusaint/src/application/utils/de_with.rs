@@ -1,4 +1,4 @@
-use serde::{Deserialize, Deserializer};
+use serde::{Deserialize, Deserializer, de::Visitor};
 
 use crate::model::SemesterType;
 
@@ -16,6 +16,30 @@ pub(crate) fn deserialize_f32_string<'de, D: Deserializer<'de>>(
     value.trim().parse().map_err(serde::de::Error::custom)
 }
 
+/// 빈 문자열을 `None`으로, 그 외의 문자열을 `f32`로 변환합니다.
+pub(crate) fn deserialize_optional_f32_string<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<f32>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    value.parse().map(Some).map_err(serde::de::Error::custom)
+}
+
+/// 빈 문자열을 `None`으로, 그 외의 문자열을 천 단위 구분 기호를 제외하고 `u32`로 변환합니다.
+pub(crate) fn deserialize_optional_u32_string<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<u32>, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    let value = value.trim().replace(',', "");
+    if value.is_empty() {
+        return Ok(None);
+    }
+    value.parse().map(Some).map_err(serde::de::Error::custom)
+}
+
 pub(crate) fn deserialize_with_trim<'de, D: Deserializer<'de>>(
     deserializer: D,
 ) -> Result<String, D::Error> {
@@ -37,16 +61,46 @@ pub(crate) fn deserialize_bool_string<'de, D: Deserializer<'de>>(
     Ok(value.trim() == "true")
 }
 
+/// 빈 문자열을 `None`으로 변환합니다. 직렬화된 `null` 값도 `None`으로 읽습니다.
 pub(crate) fn deserialize_optional_string<'de, D: Deserializer<'de>>(
     deserializer: D,
 ) -> Result<Option<String>, D::Error> {
-    let binding = String::deserialize(deserializer)?;
-    let value = binding.trim();
-    if value.is_empty() {
-        Ok(None)
-    } else {
-        Ok(Some(value.to_string()))
+    struct OptionalStringVisitor;
+
+    impl<'de> Visitor<'de> for OptionalStringVisitor {
+        type Value = Option<String>;
+
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("an optional string")
+        }
+
+        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            let value = v.trim();
+            if value.is_empty() {
+                Ok(None)
+            } else {
+                Ok(Some(value.to_string()))
+            }
+        }
+
+        fn visit_none<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: serde::de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            let value = String::deserialize(deserializer)?;
+            self.visit_str(&value)
+        }
     }
+
+    deserializer.deserialize_option(OptionalStringVisitor)
 }
 
 pub(crate) fn deserialize_semester_type<'de, D: Deserializer<'de>>(
@@ -1,6 +1,9 @@
+use std::collections::HashMap;
+
 use wdpe::{
-    element::{Element, text::InputField},
+    element::{Element, Interactable, text::InputField},
     error::{ElementError, WebDynproError},
+    event::Event,
 };
 
 pub(crate) trait InputFieldExt {
@@ -12,6 +15,9 @@ pub(crate) trait InputFieldExt {
 
     /// Converts the value of the input field to a f32.
     fn value_into_f32(&self) -> Result<f32, WebDynproError>;
+
+    /// Returns the event that changes the value of the input field.
+    fn change(&self, value: &str) -> Result<Event, WebDynproError>;
 }
 
 impl InputFieldExt for InputField<'_> {
@@ -45,4 +51,12 @@ impl InputFieldExt for InputField<'_> {
             .into()
         })
     }
+
+    fn change(&self, value: &str) -> Result<Event, WebDynproError> {
+        let parameters: HashMap<String, String> = HashMap::from([
+            ("Id".to_string(), self.id().to_string()),
+            ("Value".to_string(), value.to_string()),
+        ]);
+        self.fire_event("Change".to_string(), parameters)
+    }
 }
@@ -1,4 +1,17 @@
 pub(crate) mod de_with;
 pub(crate) mod input_field;
+pub(crate) mod popup;
 pub(crate) mod sap_table;
 pub(crate) mod semester;
+
+/// 테스트에서 `(머리글, 값)` 목록으로 테이블 행 모델을 만듭니다.
+#[cfg(test)]
+pub(crate) fn from_fields<T: serde::de::DeserializeOwned>(fields: &[(&str, &str)]) -> T {
+    use serde::de::{IntoDeserializer, value::MapDeserializer};
+    let map: std::collections::HashMap<String, String> = fields
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+    let map_de: MapDeserializer<_, serde::de::value::Error> = map.into_deserializer();
+    T::deserialize(map_de).unwrap()
+}
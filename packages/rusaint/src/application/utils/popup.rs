@@ -0,0 +1,53 @@
+use scraper::Selector;
+use wdpe::{
+    body::Body,
+    element::{
+        Element, ElementWrapper, layout::PopupWindow, parser::ElementParser, text::TextView,
+    },
+    error::WebDynproError,
+    event::Event,
+};
+
+use crate::application::USaintClient;
+
+fn control_selector(control_id: &str) -> Selector {
+    Selector::parse(format!(r#"[ct="{control_id}"]"#).as_str()).unwrap()
+}
+
+/// Returns the texts shown in the popup windows currently opened.
+pub(crate) fn popup_messages(body: &Body) -> Vec<String> {
+    let popup_selector = control_selector(PopupWindow::CONTROL_ID);
+    let text_selector = control_selector(TextView::CONTROL_ID);
+    let parser = ElementParser::new(body);
+    parser
+        .document()
+        .select(&popup_selector)
+        .flat_map(|popup| popup.select(&text_selector))
+        .filter_map(|elem| match ElementWrapper::from_ref(elem).ok()? {
+            ElementWrapper::TextView(text_view) => Some(text_view.text().trim().to_string()),
+            _ => None,
+        })
+        .filter(|text| !text.is_empty())
+        .collect()
+}
+
+/// Closes every popup window opened in the client.
+pub(crate) async fn close_popups(client: &mut USaintClient) -> Result<(), WebDynproError> {
+    let popup_selector = control_selector(PopupWindow::CONTROL_ID);
+    fn make_close_event(body: &Body, selector: &Selector) -> Option<Event> {
+        let parser = ElementParser::new(body);
+        let mut popup_iter = parser.document().select(selector);
+        popup_iter.next().and_then(|elem| {
+            let elem_wrapped = ElementWrapper::from_ref(elem).ok()?;
+            if let ElementWrapper::PopupWindow(popup) = elem_wrapped {
+                popup.close().ok()
+            } else {
+                None
+            }
+        })
+    }
+    while let Some(event) = make_close_event(client.body(), &popup_selector) {
+        client.process_event(false, event).await?;
+    }
+    Ok(())
+}
@@ -39,13 +39,28 @@ pub enum ApplicationError {
     /// 학생의 해당 학기 채플 정보가 없음
     #[error("No chapel information provided")]
     NoChapelInformation,
+    /// 학생의 장학금 수혜 내역이 없음
+    #[error("No scholarship information provided")]
+    NoScholarshipInformation,
     /// 학생의 해당 학기 시간표 정보가 없음
     #[error("No schedule information provided")]
     NoScheduleInformation,
     /// 찾고자 하는 강의의 강의평가 정보가 없음
     #[error("No Lecture assessment found")]
     NoLectureAssessments,
+    /// 찾고자 하는 강의평가의 문항별 결과가 없음
+    #[error("No lecture assessment detail found")]
+    NoLectureAssessmentDetail,
     /// 조건에 맞는 강의를 찾을 수 없음
     #[error("No lecture found")]
     NoLectureResult,
+    /// 채플 결석신청 내용이 올바르지 않음
+    #[error("Invalid chapel absence request: {0}")]
+    InvalidChapelAbsenceRequest(String),
+    /// 유세인트가 채플 결석신청 제출 또는 취소를 받아들이지 않음
+    #[error("Chapel absence request rejected: {0}")]
+    ChapelAbsenceRequestRejected(String),
+    /// 찾고자 하는 채플 결석신청이 없음
+    #[error("No chapel absence request found")]
+    NoChapelAbsenceRequest,
 }
@@ -69,6 +69,10 @@ pub use session::USaintSession;
 
 #[cfg(feature = "application")]
 /// u-saint 애플리케이션에서 공통으로 사용하는 데이터
+///
+/// 유세인트는 일자를 문자열로 표시하므로 애플리케이션 데이터는 일자를 문자열 그대로 보관합니다.
+/// 일자 필드마다 제공되는 `parsed_*` 함수는 해당 문자열을 [`Date`](model::Date)로 변환하며,
+/// 값이 비어 있거나 변환할 수 없는 형식이라면 `None`을 반환합니다.
 pub mod model;
 
 pub(crate) mod utils;
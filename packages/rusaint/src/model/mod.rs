@@ -1,10 +1,11 @@
 use serde::{Deserialize, Serialize};
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
+use thiserror::Error;
 
 /// 학기 종류
 ///
 /// 각 애플리케이션에서의 변환은 애플리케이션 내에서 직접 처리하여야 합니다.
-#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 #[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
 pub enum SemesterType {
     /// 1학기
@@ -28,3 +29,208 @@ impl Display for SemesterType {
         write!(f, "{str}")
     }
 }
+
+/// 하루 중의 시각(시, 분)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct TimeOfDay {
+    hour: u32,
+    minute: u32,
+}
+
+impl TimeOfDay {
+    /// 새로운 [`TimeOfDay`]를 만듭니다. 올바르지 않은 시각이라면 `None`을 반환합니다.
+    pub fn new(hour: u32, minute: u32) -> Option<Self> {
+        if hour < 24 && minute < 60 {
+            Some(Self { hour, minute })
+        } else {
+            None
+        }
+    }
+
+    /// 시를 반환합니다.
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    /// 분을 반환합니다.
+    pub fn minute(&self) -> u32 {
+        self.minute
+    }
+
+    /// 자정으로부터 지난 분을 반환합니다.
+    pub fn minutes_from_midnight(&self) -> u32 {
+        self.hour * 60 + self.minute
+    }
+}
+
+impl Display for TimeOfDay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+/// 문자열을 시각으로 변환할 수 없을 때 반환하는 오류
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Invalid time format: {0}")]
+pub struct ParseTimeError(String);
+
+impl FromStr for TimeOfDay {
+    type Err = ParseTimeError;
+
+    /// `hh:mm` 형태의 문자열을 시각으로 변환합니다.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseTimeError(s.to_string());
+        let (hour, minute) = s.trim().split_once(':').ok_or_else(err)?;
+        let hour = hour.trim().parse().map_err(|_| err())?;
+        let minute = minute.trim().parse().map_err(|_| err())?;
+        Self::new(hour, minute).ok_or_else(err)
+    }
+}
+
+/// 날짜(년, 월, 일)
+///
+/// 유세인트에서 사용하는 `YYYY.MM.DD`, `YYYY-MM-DD`, `YYYY/MM/DD`, `YYYYMMDD` 형식의 문자열로부터 변환할 수 있습니다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Date {
+    year: u32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// 새로운 [`Date`]를 만듭니다. 올바르지 않은 날짜라면 `None`을 반환합니다.
+    pub fn new(year: u32, month: u32, day: u32) -> Option<Self> {
+        let days_in_month = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if year.is_multiple_of(4)
+                && (!year.is_multiple_of(100) || year.is_multiple_of(400)) =>
+            {
+                29
+            }
+            2 => 28,
+            _ => return None,
+        };
+        (year > 0 && (1..=days_in_month).contains(&day)).then_some(Self { year, month, day })
+    }
+
+    /// 년을 반환합니다.
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// 월을 반환합니다.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// 일을 반환합니다.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// 1970년 1월 1일로부터 지난 일수를 반환합니다.
+    pub fn days_since_epoch(&self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from(self.month);
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146097 + day_of_era - 719468
+    }
+
+    /// 1970년 1월 1일로부터 지난 일수로 날짜를 만듭니다.
+    pub fn from_days_since_epoch(days: i64) -> Option<Self> {
+        let days = days + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days - era * 146097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Self::new(
+            u32::try_from(year).ok()?,
+            u32::try_from(month).ok()?,
+            u32::try_from(day).ok()?,
+        )
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// 문자열을 날짜로 변환할 수 없을 때 반환하는 오류
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("Invalid date format: {0}")]
+pub struct ParseDateError(String);
+
+impl FromStr for Date {
+    type Err = ParseDateError;
+
+    /// `YYYY.MM.DD`, `YYYY-MM-DD`, `YYYY/MM/DD`, `YYYYMMDD` 형태의 문자열을 날짜로 변환합니다.
+    /// 날짜 뒤에 공백으로 구분된 시각이 있다면 무시합니다.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseDateError(s.to_string());
+        let date = s.split_whitespace().next().ok_or_else(err)?;
+        let parts: Vec<&str> = if date.chars().all(|char| char.is_ascii_digit()) {
+            if date.len() != 8 {
+                return Err(err());
+            }
+            vec![&date[..4], &date[4..6], &date[6..]]
+        } else {
+            date.split(['.', '-', '/'])
+                .filter(|part| !part.is_empty())
+                .collect()
+        };
+        let [year, month, day] = parts.as_slice() else {
+            return Err(err());
+        };
+        let year = year.parse().map_err(|_| err())?;
+        let month = month.parse().map_err(|_| err())?;
+        let day = day.parse().map_err(|_| err())?;
+        Self::new(year, month, day).ok_or_else(err)
+    }
+}
+
+/// 빈 문자열은 `None`으로, 그 외의 문자열은 날짜로 변환합니다. 변환할 수 없는 문자열이라면 `None`을 반환합니다.
+pub(crate) fn parse_date(date: &str) -> Option<Date> {
+    date.parse().ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::Date;
+
+    #[test]
+    fn parse_date() {
+        let date = Date::new(2025, 3, 18).unwrap();
+        assert_eq!("2025.03.18".parse::<Date>(), Ok(date));
+        assert_eq!("2025-03-18".parse::<Date>(), Ok(date));
+        assert_eq!("20250318".parse::<Date>(), Ok(date));
+        assert_eq!("2025.03.18 10:30:00".parse::<Date>(), Ok(date));
+        assert!("2025.02.29".parse::<Date>().is_err());
+        assert!("".parse::<Date>().is_err());
+        assert_eq!(date.to_string(), "2025-03-18");
+    }
+
+    #[test]
+    fn days_since_epoch() {
+        assert_eq!(Date::new(1970, 1, 1).unwrap().days_since_epoch(), 0);
+        let date = Date::new(2024, 2, 29).unwrap();
+        assert_eq!(date.days_since_epoch(), 19782);
+        assert_eq!(Date::from_days_since_epoch(19782), Some(date));
+    }
+}
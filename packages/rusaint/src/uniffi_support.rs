@@ -1,3 +1,7 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::model::Date;
+
 #[derive(uniffi::Record)]
 /// uniffi 지원을 위한 u32 Pair입니다.
 pub struct UnsignedIntPair {
@@ -15,3 +19,28 @@ uniffi::custom_type!(U32Pair, UnsignedIntPair, {
     },
     try_lift: |val| Ok((val.first, val.second))
 });
+
+/// 한국 표준시(UTC+9)의 UTC와의 시차(초)
+const KST_OFFSET_SECONDS: i64 = 9 * 60 * 60;
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+// 날짜는 한국 표준시 기준 해당 날짜의 자정으로 변환됩니다.
+uniffi::custom_type!(Date, SystemTime, {
+    lower: |date| {
+        let seconds = date.days_since_epoch() * SECONDS_PER_DAY - KST_OFFSET_SECONDS;
+        if seconds >= 0 {
+            UNIX_EPOCH + Duration::from_secs(seconds.unsigned_abs())
+        } else {
+            UNIX_EPOCH - Duration::from_secs(seconds.unsigned_abs())
+        }
+    },
+    try_lift: |time| {
+        let seconds = match time.duration_since(UNIX_EPOCH) {
+            Ok(duration) => i64::try_from(duration.as_secs())?,
+            Err(err) => -i64::try_from(err.duration().as_secs())?,
+        };
+        let days = (seconds + KST_OFFSET_SECONDS).div_euclid(SECONDS_PER_DAY);
+        Date::from_days_since_epoch(days).ok_or_else(|| uniffi::deps::anyhow::anyhow!("Date out of range"))
+    }
+});
@@ -56,3 +56,13 @@ async fn no_chapel() {
     tracing::info!("{:?}", info);
     tracing::info!("{:?}", info);
 }
+
+#[tokio::test]
+#[traced_test]
+async fn history() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let history = app.history().await.unwrap();
+    tracing::info!("passed: {}", history.passed_semesters());
+    tracing::info!("{:?}", history);
+}
@@ -98,3 +98,37 @@ async fn classes_with_detail() {
     tracing::info!("{:?}", detail);
     assert!(!detail.is_empty());
 }
+
+#[tokio::test]
+#[traced_test]
+async fn all_classes() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let grades = app.all_classes(CourseType::Bachelor, false).await.unwrap();
+    for semester in grades.semesters() {
+        tracing::info!(
+            "{} {}: {:?}",
+            semester.year(),
+            semester.semester(),
+            semester.classes()
+        );
+    }
+    tracing::info!("{:?}", grades.failures());
+    assert!(!grades.semesters().is_empty());
+}
+
+#[tokio::test]
+#[traced_test]
+async fn academic_history() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let history = app.academic_history().await.unwrap();
+    assert!(history.program(CourseType::Bachelor).is_some());
+    assert!(
+        history
+            .programs()
+            .iter()
+            .all(|program| !program.semesters().is_empty())
+    );
+    tracing::info!("{:?}", history);
+}
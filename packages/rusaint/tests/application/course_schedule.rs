@@ -211,3 +211,22 @@ async fn find_nothing() {
         RusaintError::ApplicationError(ApplicationError::NoLectureResult)
     ));
 }
+
+#[tokio::test]
+#[traced_test]
+async fn lecture_history() {
+    let lock = get_app().await.unwrap();
+    let mut app = lock.write().await;
+    let history = app.lecture_history("컴퓨터구조", 2024, 2025).await.unwrap();
+    for offering in history.offerings() {
+        tracing::info!(
+            "{} {:?}: {:?} ({})",
+            offering.year(),
+            offering.semester(),
+            offering.professors(),
+            offering.capacity()
+        );
+    }
+    tracing::info!("{:?}", history.changes());
+    assert!(!history.offerings().is_empty());
+}
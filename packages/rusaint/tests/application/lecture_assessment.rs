@@ -1,6 +1,9 @@
 use crate::get_session;
 use rusaint::{
-    application::{USaintClientBuilder, lecture_assessment::LectureAssessmentApplication},
+    application::{
+        USaintClientBuilder,
+        lecture_assessment::{LectureAssessmentApplication, statistics::AssessmentTarget},
+    },
     model::SemesterType,
 };
 use tracing_test::traced_test;
@@ -21,3 +24,45 @@ async fn lecture_assessment() {
     assert_eq!(info.len(), 29);
     tracing::info!("{} results: {:?}", info.len(), info);
 }
+
+#[tokio::test]
+#[traced_test]
+async fn assessment_detail() {
+    let session = get_session().await.unwrap().clone();
+    let mut app = USaintClientBuilder::new()
+        .session(session)
+        .build_into::<LectureAssessmentApplication>()
+        .await
+        .unwrap();
+    let info = app
+        .find_assessments(2023, SemesterType::Two, Some("마케팅"), None, None)
+        .await
+        .unwrap();
+    let detail = app.assessment_detail(&info[0]).await.unwrap();
+    assert!(!detail.questions().is_empty());
+    tracing::info!("{:?}", detail);
+}
+
+#[tokio::test]
+#[traced_test]
+async fn assessment_statistics() {
+    let session = get_session().await.unwrap().clone();
+    let mut app = USaintClientBuilder::new()
+        .session(session)
+        .build_into::<LectureAssessmentApplication>()
+        .await
+        .unwrap();
+    let info = app
+        .find_assessments(2023, SemesterType::Two, Some("마케팅"), None, None)
+        .await
+        .unwrap();
+    let target = AssessmentTarget::Lecture {
+        code: info[0].lecture_code(),
+    };
+    let statistics = app
+        .assessment_statistics(&target, 2022, 2023)
+        .await
+        .unwrap();
+    assert!(!statistics.semesters().is_empty());
+    tracing::info!("{:?}", statistics);
+}
@@ -1,6 +1,7 @@
 use crate::get_session;
 use rusaint::application::USaintClientBuilder;
 use rusaint::application::scholarships::ScholarshipsApplication;
+use rusaint::application::scholarships::summary::ScholarshipFilter;
 use tracing_test::traced_test;
 
 #[tokio::test]
@@ -15,3 +16,19 @@ async fn scholarships() {
     let info = app.scholarships().await.unwrap();
     tracing::info!("{:?}", info);
 }
+
+#[tokio::test]
+#[traced_test]
+async fn scholarship_summary() {
+    let session = get_session().await.unwrap().clone();
+    let mut app = USaintClientBuilder::new()
+        .session(session)
+        .build_into::<ScholarshipsApplication>()
+        .await
+        .unwrap();
+    let summary = app
+        .scholarship_summary(&ScholarshipFilter::new())
+        .await
+        .unwrap();
+    tracing::info!("{:?}", summary);
+}